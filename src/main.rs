@@ -1,11 +1,115 @@
-mod models;
-use models::{controller::CLIController, game::Game};
+use std::io::IsTerminal;
+
+use clap::{Parser, ValueEnum};
+use hearts_game::models::{
+    card::RankOrder,
+    config::{ConfigError, GameConfig, SunRule, DEFAULT_PLAYER_COUNT, DEFAULT_TARGET_SCORE},
+    controller::CLIController,
+    game::Game,
+};
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Variant {
+    JackOfDiamonds,
+    QueenBreaksHearts,
+    NoQueenPass,
+    ShootTheSun,
+    AceLow,
+    QueenOnFirstTrick,
+}
+
+#[derive(Parser)]
+#[command(name = "hearts", about = "Play Hearts in the terminal.")]
+struct Args {
+    #[arg(long)]
+    seed: Option<u64>,
+
+    #[arg(long, default_value_t = DEFAULT_PLAYER_COUNT)]
+    players: usize,
+
+    #[arg(long, default_value_t = DEFAULT_TARGET_SCORE)]
+    target: i16,
+
+    #[arg(long = "variant", value_enum)]
+    variants: Vec<Variant>,
+
+    #[arg(long)]
+    no_pass: bool,
+
+    #[arg(long)]
+    auto_play: bool,
+
+    #[arg(long)]
+    hints: bool,
+}
+
+impl Args {
+    fn config(&self) -> Result<GameConfig, ConfigError> {
+        let mut builder = GameConfig::builder()
+            .player_count(self.players)
+            .target_score(self.target)
+            .no_passing(self.no_pass)
+            .auto_play_forced(self.auto_play);
+        if let Some(seed) = self.seed {
+            builder = builder.seed(seed);
+        }
+        for variant in &self.variants {
+            builder = match variant {
+                Variant::JackOfDiamonds => builder.jack_of_diamonds(true),
+                Variant::QueenBreaksHearts => builder.queen_breaks_hearts(true),
+                Variant::NoQueenPass => builder.allow_passing_queen(false),
+                Variant::ShootTheSun => builder.sun_rule(SunRule::AddToOthers),
+                Variant::AceLow => builder.rank_order(RankOrder::AceLow),
+                Variant::QueenOnFirstTrick => builder.queen_allowed_first_trick(true),
+            };
+        }
+
+        builder.build()
+    }
+}
 
-const MAX_SCORE: u8 = 100;
 fn main() -> anyhow::Result<()> {
+    let args = Args::parse();
+    let config = args.config()?;
+
     println!("{:-^30}\n", "HEARTS");
-    let mut game = Game::new(CLIController)?;
-    while game.round()? < MAX_SCORE {}
+    let controller = CLIController::new(std::io::stdout().is_terminal()).with_hints(args.hints);
+    let result = Game::new(config, controller).and_then(|mut game| game.play());
+    match result {
+        Err(error) if error.is_cancelled() => println!("\nGame aborted."),
+        result => {
+            result?;
+        }
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<GameConfig, ConfigError> {
+        Args::try_parse_from(["hearts"].iter().chain(args)).unwrap().config()
+    }
+
+    #[test]
+    fn arguments_build_a_config() {
+        let config =
+            parse(&["--seed", "42", "--players", "5", "--target", "50", "--variant", "jack-of-diamonds"])
+                .unwrap();
+
+        assert_eq!(config.seed, Some(42));
+        assert_eq!(config.player_count, 5);
+        assert_eq!(config.target_score, 50);
+        assert!(config.jack_of_diamonds);
+        assert!(!config.queen_breaks_hearts);
+        assert_eq!(parse(&[]).unwrap(), GameConfig::default());
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected_before_the_game() {
+        assert!(matches!(parse(&["--players", "7"]), Err(ConfigError::PlayerCountError(7))));
+        assert!(matches!(parse(&["--target", "0"]), Err(ConfigError::TargetScoreError(0))));
+    }
+}
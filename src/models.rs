@@ -1,5 +1,14 @@
+#[cfg(feature = "async")]
+pub mod async_controller;
 pub mod card;
+pub mod config;
 pub mod controller;
 pub mod deck;
+pub mod events;
 pub mod game;
+#[cfg(feature = "network")]
+pub mod network_controller;
+pub mod observer;
 pub mod player;
+pub mod state;
+pub mod tracker;
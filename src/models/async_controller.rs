@@ -0,0 +1,118 @@
+use async_trait::async_trait;
+
+use super::{
+    card::Card,
+    controller::ControllerError,
+    game::{MoonOption, PassingOrder},
+    observer::Observer,
+    player::{Player, PlayerId},
+};
+
+type ControllerResult<T> = Result<T, ControllerError>;
+
+#[async_trait(?Send)]
+pub trait AsyncController: Observer {
+    async fn get_names(&self, count: usize) -> ControllerResult<Vec<String>>;
+
+    async fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>>;
+
+    async fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card>;
+
+    async fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        future::Future,
+        pin::pin,
+        sync::{
+            mpsc::{self, Receiver},
+            Arc,
+        },
+        task::{Context, Poll, Wake, Waker},
+    };
+
+    use iter_tools::Itertools;
+
+    use super::*;
+    use crate::models::{
+        config::GameConfig,
+        deck::fixtures::{suited_deck, suited_tricks},
+        game::Game,
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut context = Context::from_waker(&waker);
+        let mut future = pin!(future);
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+                return output;
+            }
+        }
+    }
+
+    struct ChannelController {
+        names: Vec<String>,
+        placements: Receiver<Card>,
+    }
+
+    #[async_trait(?Send)]
+    impl AsyncController for ChannelController {
+        async fn get_names(&self, _count: usize) -> ControllerResult<Vec<String>> {
+            Ok(self.names.clone())
+        }
+
+        async fn get_cards_to_pass(
+            &self, _from: &Player, _to: &Player, _passing_order: &PassingOrder, _count: usize,
+            _allow_queen: bool, _suggested: &[Card],
+        ) -> ControllerResult<Vec<Card>> {
+            Err(ControllerError::Failed)
+        }
+
+        async fn get_card_to_place(
+            &self, _player: &Player, _table: &[(PlayerId, Card)], _is_first_trick: bool,
+            _hearts_broken: bool, _queen_allowed_first_trick: bool,
+        ) -> ControllerResult<Card> {
+            self.placements.try_recv().map_err(|_| ControllerError::Failed)
+        }
+
+        async fn choose_moon_option(&self, _shooter: &Player) -> ControllerResult<MoonOption> {
+            Ok(MoonOption::AddToOthers)
+        }
+    }
+
+    impl Observer for ChannelController {}
+
+    #[test]
+    fn async_controller_plays_a_scripted_round() {
+        let (sender, placements) = mpsc::channel();
+        for card in suited_tricks().into_iter().flatten() {
+            sender.send(card).unwrap();
+        }
+        let names = (1..=4).map(|i| format!("Player {i}")).collect();
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+
+        let mut game = block_on(Game::new_async(config, ChannelController { names, placements }))
+            .unwrap()
+            .with_deck(suited_deck());
+        let result = block_on(game.round_async()).unwrap();
+
+        assert_eq!(result.tricks_won, vec![13, 0, 0, 0]);
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![0, 26, 26, 26]);
+    }
+}
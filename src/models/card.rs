@@ -1,9 +1,19 @@
-use std::fmt::Display;
+use std::{cmp::Ordering, fmt::Display, str::FromStr};
 
 use derivative::Derivative;
+use iter_tools::Itertools;
+use strum::IntoEnumIterator;
 use strum_macros::{Display, EnumIter};
+use thiserror::Error;
 
-#[derive(Clone, Copy, Display, Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord)]
+use super::config::ScoringRules;
+
+pub const QUEEN_OF_SPADES_POINTS: u8 = 13;
+pub const HEART_POINTS: u8 = 1;
+pub const TOTAL_ROUND_POINTS: u8 = 26;
+
+#[derive(Clone, Copy, Display, Debug, EnumIter, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Rank {
     Two,
     Three,
@@ -20,7 +30,49 @@ pub enum Rank {
     Ace,
 }
 
-#[derive(Clone, Copy, Display, Debug, EnumIter, PartialEq, Eq)]
+impl Rank {
+    pub fn value(&self) -> u8 {
+        *self as u8 + 2
+    }
+
+    pub fn short(&self) -> &'static str {
+        match self {
+            Self::Two => "2",
+            Self::Three => "3",
+            Self::Four => "4",
+            Self::Five => "5",
+            Self::Six => "6",
+            Self::Seven => "7",
+            Self::Eight => "8",
+            Self::Nine => "9",
+            Self::Ten => "10",
+            Self::Jack => "J",
+            Self::Queen => "Q",
+            Self::King => "K",
+            Self::Ace => "A",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RankOrder {
+    #[default]
+    AceHigh,
+    AceLow,
+}
+
+impl RankOrder {
+    pub fn value(&self, rank: Rank) -> u8 {
+        match (self, rank) {
+            (Self::AceLow, Rank::Ace) => 1,
+            _ => rank.value(),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Display, Debug, EnumIter, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Suit {
     Hearts,
     Clubs,
@@ -28,7 +80,56 @@ pub enum Suit {
     Spades,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Derivative)]
+impl Suit {
+    pub fn short(&self) -> char {
+        match self {
+            Self::Hearts => 'H',
+            Self::Clubs => 'C',
+            Self::Diamonds => 'D',
+            Self::Spades => 'S',
+        }
+    }
+
+    pub fn is_red(&self) -> bool {
+        matches!(self, Self::Hearts | Self::Diamonds)
+    }
+
+    pub fn iter_in_display_order() -> impl Iterator<Item = Self> {
+        SuitOrder::default().suits().into_iter()
+    }
+
+    pub fn symbol(&self) -> char {
+        match self {
+            Self::Hearts => '♥',
+            Self::Clubs => '♣',
+            Self::Diamonds => '♦',
+            Self::Spades => '♠',
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SuitOrder {
+    #[default]
+    Bridge,
+    Alternating,
+}
+
+impl SuitOrder {
+    pub fn suits(&self) -> [Suit; 4] {
+        match self {
+            Self::Bridge => [Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades],
+            Self::Alternating => [Suit::Clubs, Suit::Hearts, Suit::Spades, Suit::Diamonds],
+        }
+    }
+
+    pub fn position(&self, suit: Suit) -> usize {
+        self.suits().iter().position(|ordered| *ordered == suit).expect("Every suit should be ordered")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Derivative)]
 #[derivative(PartialOrd, Ord)]
 pub struct Card {
     pub rank: Rank,
@@ -42,10 +143,18 @@ impl Card {
         Self { rank, suit }
     }
 
+    pub fn all() -> Vec<Self> {
+        Rank::iter().cartesian_product(Suit::iter()).map(|(rank, suit)| Self::new(rank, suit)).collect()
+    }
+
     pub fn score(&self) -> u8 {
+        self.score_with(&ScoringRules::default())
+    }
+
+    pub fn score_with(&self, scoring: &ScoringRules) -> u8 {
         match (&self.rank, &self.suit) {
-            (Rank::Queen, Suit::Spades) => 13,
-            (_, Suit::Hearts) => 1,
+            (Rank::Queen, Suit::Spades) => scoring.queen_points,
+            (_, Suit::Hearts) => scoring.heart_points,
             _ => 0,
         }
     }
@@ -57,10 +166,337 @@ impl Card {
     pub fn is_hearts(&self) -> bool {
         self.suit == Suit::Hearts
     }
+
+    pub fn is_queen_of_spades(&self) -> bool {
+        self.rank == Rank::Queen && self.suit == Suit::Spades
+    }
+
+    pub fn is_jack_of_diamonds(&self) -> bool {
+        self.rank == Rank::Jack && self.suit == Suit::Diamonds
+    }
+
+    pub fn is_point_card(&self) -> bool {
+        self.is_hearts() || self.is_queen_of_spades()
+    }
+
+    pub fn beats(&self, other: &Self, led_suit: Suit) -> bool {
+        self.beats_with(other, led_suit, RankOrder::AceHigh)
+    }
+
+    pub fn beats_with(&self, other: &Self, led_suit: Suit, rank_order: RankOrder) -> bool {
+        match (self.suit == led_suit, other.suit == led_suit) {
+            (true, true) => rank_order.value(self.rank) > rank_order.value(other.rank),
+            (true, false) => true,
+            (false, _) => false,
+        }
+    }
+
+    pub fn cmp_by_suit_then_rank(&self, other: &Self) -> Ordering {
+        self.cmp_with_suit_order(other, SuitOrder::default())
+    }
+
+    pub fn cmp_with_suit_order(&self, other: &Self, suit_order: SuitOrder) -> Ordering {
+        suit_order.position(self.suit).cmp(&suit_order.position(other.suit)).then(self.rank.cmp(&other.rank))
+    }
+
+    pub fn short(&self) -> String {
+        format!("{}{}", self.rank.short(), self.suit.short())
+    }
+}
+
+impl From<(Rank, Suit)> for Card {
+    fn from((rank, suit): (Rank, Suit)) -> Self {
+        Self::new(rank, suit)
+    }
+}
+
+#[macro_export]
+macro_rules! card {
+    (@rank 2) => { $crate::models::card::Rank::Two };
+    (@rank 3) => { $crate::models::card::Rank::Three };
+    (@rank 4) => { $crate::models::card::Rank::Four };
+    (@rank 5) => { $crate::models::card::Rank::Five };
+    (@rank 6) => { $crate::models::card::Rank::Six };
+    (@rank 7) => { $crate::models::card::Rank::Seven };
+    (@rank 8) => { $crate::models::card::Rank::Eight };
+    (@rank 9) => { $crate::models::card::Rank::Nine };
+    (@rank 10) => { $crate::models::card::Rank::Ten };
+    (@rank J) => { $crate::models::card::Rank::Jack };
+    (@rank Q) => { $crate::models::card::Rank::Queen };
+    (@rank K) => { $crate::models::card::Rank::King };
+    (@rank A) => { $crate::models::card::Rank::Ace };
+    (@suit H) => { $crate::models::card::Suit::Hearts };
+    (@suit C) => { $crate::models::card::Suit::Clubs };
+    (@suit D) => { $crate::models::card::Suit::Diamonds };
+    (@suit S) => { $crate::models::card::Suit::Spades };
+    ($rank:tt, $suit:tt) => {
+        $crate::models::card::Card::new($crate::card!(@rank $rank), $crate::card!(@suit $suit))
+    };
 }
 
 impl Display for Card {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} of {}", self.rank, self.suit)
+        if f.alternate() {
+            write!(f, "{}{}", self.rank.short(), self.suit.symbol())
+        } else {
+            write!(f, "{} of {}", self.rank, self.suit)
+        }
+    }
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CardParseError {
+    #[error("Card shorthand is empty.")]
+    Empty,
+    #[error("Card shorthand \"{0}\" is malformed.")]
+    Malformed(String),
+    #[error("Unknown rank \"{0}\".")]
+    UnknownRank(String),
+    #[error("Unknown suit \"{0}\".")]
+    UnknownSuit(char),
+}
+
+impl FromStr for Card {
+    type Err = CardParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let Some(suit_char) = s.chars().last() else {
+            return Err(CardParseError::Empty);
+        };
+
+        let rank_str = &s[..s.len() - suit_char.len_utf8()];
+        if rank_str.is_empty() {
+            return Err(CardParseError::Malformed(s.to_owned()));
+        }
+
+        let rank = match rank_str.to_ascii_uppercase().as_str() {
+            "2" => Rank::Two,
+            "3" => Rank::Three,
+            "4" => Rank::Four,
+            "5" => Rank::Five,
+            "6" => Rank::Six,
+            "7" => Rank::Seven,
+            "8" => Rank::Eight,
+            "9" => Rank::Nine,
+            "10" => Rank::Ten,
+            "J" => Rank::Jack,
+            "Q" => Rank::Queen,
+            "K" => Rank::King,
+            "A" => Rank::Ace,
+            _ => return Err(CardParseError::UnknownRank(rank_str.to_owned())),
+        };
+
+        let suit = match suit_char.to_ascii_uppercase() {
+            'H' | '♥' => Suit::Hearts,
+            'C' | '♣' => Suit::Clubs,
+            'D' | '♦' => Suit::Diamonds,
+            'S' | '♠' => Suit::Spades,
+            _ => return Err(CardParseError::UnknownSuit(suit_char)),
+        };
+
+        Ok(Self::new(rank, suit))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Card {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.short())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Card {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        <String as serde::Deserialize>::deserialize(deserializer)?.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn renders_short_form() {
+        assert_eq!(Card::new(Rank::Queen, Suit::Spades).short(), "QS");
+        assert_eq!(Card::new(Rank::Ten, Suit::Hearts).short(), "10H");
+        assert_eq!(Card::new(Rank::Two, Suit::Clubs).short(), "2C");
+        assert_eq!(Card::new(Rank::Ace, Suit::Diamonds).short(), "AD");
+        assert_eq!(format!("{:#}", Card::new(Rank::Ten, Suit::Spades)), "10♠");
+        assert_eq!(Card::new(Rank::Jack, Suit::Clubs).to_string(), "Jack of Clubs");
+    }
+
+    #[test]
+    fn parses_every_card() {
+        for card in Card::all() {
+            assert_eq!(card.short().parse::<Card>(), Ok(card));
+            assert_eq!(card.short().to_lowercase().parse::<Card>(), Ok(card));
+            assert_eq!(format!("{card:#}").parse::<Card>(), Ok(card));
+        }
+        assert_eq!(" 10h ".parse::<Card>(), Ok(Card::new(Rank::Ten, Suit::Hearts)));
+    }
+
+    #[test]
+    fn rejects_malformed_cards() {
+        assert_eq!("".parse::<Card>(), Err(CardParseError::Empty));
+        assert_eq!("Q".parse::<Card>(), Err(CardParseError::Malformed("Q".to_owned())));
+        assert_eq!("ZZ".parse::<Card>(), Err(CardParseError::UnknownRank("Z".to_owned())));
+        assert_eq!("1H".parse::<Card>(), Err(CardParseError::UnknownRank("1".to_owned())));
+        assert_eq!("QX".parse::<Card>(), Err(CardParseError::UnknownSuit('X')));
+    }
+
+    #[test]
+    fn all_cards_are_unique() {
+        let cards = Card::all();
+
+        assert_eq!(cards.len(), 52);
+        assert_eq!(cards.iter().collect::<HashSet<_>>().len(), 52);
+    }
+
+    #[test]
+    fn classifies_point_cards() {
+        let queen_of_spades = Card::new(Rank::Queen, Suit::Spades);
+        assert!(queen_of_spades.is_queen_of_spades());
+        assert!(queen_of_spades.is_point_card());
+
+        for rank in Rank::iter() {
+            assert!(Card::new(rank, Suit::Hearts).is_point_card());
+        }
+
+        for card in [
+            Card::new(Rank::Queen, Suit::Clubs),
+            Card::new(Rank::King, Suit::Spades),
+            Card::new(Rank::Jack, Suit::Diamonds),
+            Card::new(Rank::Two, Suit::Clubs),
+        ] {
+            assert!(!card.is_queen_of_spades());
+            assert!(!card.is_point_card());
+        }
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn cards_round_trip_through_json() {
+        for card in Card::all() {
+            let json = serde_json::to_string(&card).unwrap();
+            assert_eq!(json, format!("\"{}\"", card.short()));
+            assert_eq!(serde_json::from_str::<Card>(&json).unwrap(), card);
+        }
+        for rank in Rank::iter() {
+            assert_eq!(serde_json::from_str::<Rank>(&serde_json::to_string(&rank).unwrap()).unwrap(), rank);
+        }
+        for suit in Suit::iter() {
+            assert_eq!(serde_json::from_str::<Suit>(&serde_json::to_string(&suit).unwrap()).unwrap(), suit);
+        }
+    }
+
+    #[test]
+    fn suits_render_as_symbols() {
+        assert_eq!(Suit::Hearts.symbol(), '♥');
+        assert_eq!(Suit::Clubs.symbol(), '♣');
+        assert_eq!(Suit::Diamonds.symbol(), '♦');
+        assert_eq!(Suit::Spades.symbol(), '♠');
+        for suit in Suit::iter() {
+            assert!(format!("{:#}", Card::new(Rank::Ace, suit)).ends_with(suit.symbol()));
+        }
+    }
+
+    #[test]
+    fn hearts_and_diamonds_are_red() {
+        assert!(Suit::Hearts.is_red());
+        assert!(Suit::Diamonds.is_red());
+        assert!(!Suit::Clubs.is_red());
+        assert!(!Suit::Spades.is_red());
+    }
+
+    #[test]
+    fn off_suit_cards_never_beat_the_led_suit() {
+        let two_of_clubs = Card::new(Rank::Two, Suit::Clubs);
+        let ace_of_hearts = Card::new(Rank::Ace, Suit::Hearts);
+        let king_of_clubs = Card::new(Rank::King, Suit::Clubs);
+
+        assert!(!ace_of_hearts.beats(&two_of_clubs, Suit::Clubs));
+        assert!(two_of_clubs.beats(&ace_of_hearts, Suit::Clubs));
+        assert!(king_of_clubs.beats(&two_of_clubs, Suit::Clubs));
+        assert!(!two_of_clubs.beats(&king_of_clubs, Suit::Clubs));
+    }
+
+    #[test]
+    fn ranks_have_numeric_values() {
+        assert_eq!(Rank::iter().map(|rank| rank.value()).collect::<Vec<_>>(), (2..=14).collect::<Vec<_>>());
+        assert_eq!(Rank::Two.value(), 2);
+        assert_eq!(Rank::Ten.value(), 10);
+        assert_eq!(Rank::Jack.value(), 11);
+        assert_eq!(Rank::Ace.value(), 14);
+    }
+
+    #[test]
+    fn higher_cards_of_the_led_suit_win() {
+        let five_of_spades = Card::new(Rank::Five, Suit::Spades);
+        let nine_of_spades = Card::new(Rank::Nine, Suit::Spades);
+        let ace_of_diamonds = Card::new(Rank::Ace, Suit::Diamonds);
+
+        assert!(nine_of_spades.beats(&five_of_spades, Suit::Spades));
+        assert!(!five_of_spades.beats(&nine_of_spades, Suit::Spades));
+        assert!(five_of_spades.beats(&ace_of_diamonds, Suit::Spades));
+        assert!(!ace_of_diamonds.beats(&five_of_spades, Suit::Spades));
+        assert!(ace_of_diamonds.beats(&nine_of_spades, Suit::Diamonds));
+    }
+
+    #[test]
+    fn ace_low_lets_the_king_beat_the_ace() {
+        let king = Card::new(Rank::King, Suit::Hearts);
+        let ace = Card::new(Rank::Ace, Suit::Hearts);
+
+        assert!(king.beats_with(&ace, Suit::Hearts, RankOrder::AceLow));
+        assert!(!ace.beats_with(&king, Suit::Hearts, RankOrder::AceLow));
+        assert!(ace.beats_with(&king, Suit::Hearts, RankOrder::AceHigh));
+        assert_eq!(RankOrder::AceLow.value(Rank::Ace), 1);
+        assert_eq!(RankOrder::AceLow.value(Rank::Two), 2);
+    }
+
+    #[test]
+    fn deck_points_add_up_to_the_round_total() {
+        let total: u8 = Card::all().iter().map(Card::score).sum();
+
+        assert_eq!(total, TOTAL_ROUND_POINTS);
+        assert_eq!(Card::new(Rank::Queen, Suit::Spades).score(), QUEEN_OF_SPADES_POINTS);
+        assert_eq!(Card::new(Rank::Two, Suit::Hearts).score(), HEART_POINTS);
+    }
+
+    #[test]
+    fn shorthand_constructors_match_card_new() {
+        assert_eq!(card!(Q, S), Card::new(Rank::Queen, Suit::Spades));
+        assert_eq!(card!(10, H), Card::new(Rank::Ten, Suit::Hearts));
+        assert_eq!(card!(2, C), Card::new(Rank::Two, Suit::Clubs));
+        assert_eq!(card!(A, D), Card::new(Rank::Ace, Suit::Diamonds));
+
+        for card in Card::all() {
+            assert_eq!(Card::from((card.rank, card.suit)), card);
+        }
+    }
+
+    #[test]
+    fn suits_display_in_conventional_order() {
+        assert_eq!(
+            Suit::iter_in_display_order().collect::<Vec<_>>(),
+            vec![Suit::Clubs, Suit::Diamonds, Suit::Hearts, Suit::Spades]
+        );
+        assert_eq!(SuitOrder::Alternating.suits(), [Suit::Clubs, Suit::Hearts, Suit::Spades, Suit::Diamonds]);
+
+        let mut hand = vec![
+            Card::new(Rank::Two, Suit::Spades),
+            Card::new(Rank::Ace, Suit::Hearts),
+            Card::new(Rank::Five, Suit::Diamonds),
+            Card::new(Rank::King, Suit::Clubs),
+        ];
+        hand.sort_by(Card::cmp_by_suit_then_rank);
+        assert_eq!(
+            hand.iter().map(|card| card.suit).collect::<Vec<_>>(),
+            Suit::iter_in_display_order().collect::<Vec<_>>()
+        );
     }
 }
@@ -0,0 +1,252 @@
+use thiserror::Error;
+
+use super::card::{Card, RankOrder, HEART_POINTS, QUEEN_OF_SPADES_POINTS};
+
+pub const DEFAULT_PLAYER_COUNT: usize = 4;
+pub const DEFAULT_TARGET_SCORE: i16 = 100;
+pub const DEFAULT_PASS_COUNT: usize = 3;
+pub const DEFAULT_JACK_OF_DIAMONDS_POINTS: i16 = -10;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoonRule {
+    #[default]
+    AddToOthers,
+    SubtractFromSelf,
+    PlayerChoice,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SunRule {
+    AddToOthers,
+    SubtractFromSelf,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScoringRules {
+    pub heart_points: u8,
+    pub queen_points: u8,
+    pub jack_of_diamonds_points: i16,
+}
+
+impl ScoringRules {
+    pub fn total_points(&self) -> u16 {
+        Card::all().iter().map(|card| u16::from(card.score_with(self))).sum()
+    }
+}
+
+impl Default for ScoringRules {
+    fn default() -> Self {
+        Self {
+            heart_points: HEART_POINTS,
+            queen_points: QUEEN_OF_SPADES_POINTS,
+            jack_of_diamonds_points: DEFAULT_JACK_OF_DIAMONDS_POINTS,
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum ConfigError {
+    #[error("Hearts needs between 3 and 6 players, not {0}.")]
+    PlayerCountError(usize),
+    #[error("The target score must be positive, not {0}.")]
+    TargetScoreError(i16),
+    #[error("Players must pass between 1 and {hand_size} cards, not {pass_count}.")]
+    PassCountError { pass_count: usize, hand_size: usize },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameConfig {
+    pub player_count: usize,
+    pub target_score: i16,
+    pub pass_count: usize,
+    pub allow_passing_queen: bool,
+    pub no_passing: bool,
+    pub moon_rule: MoonRule,
+    pub sun_rule: Option<SunRule>,
+    pub jack_of_diamonds: bool,
+    pub queen_breaks_hearts: bool,
+    pub queen_allowed_first_trick: bool,
+    pub rank_order: RankOrder,
+    pub scoring: ScoringRules,
+    pub auto_play_forced: bool,
+    pub seed: Option<u64>,
+}
+
+impl GameConfig {
+    pub fn builder() -> GameConfigBuilder {
+        GameConfigBuilder::default()
+    }
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        Self {
+            player_count: DEFAULT_PLAYER_COUNT,
+            target_score: DEFAULT_TARGET_SCORE,
+            pass_count: DEFAULT_PASS_COUNT,
+            allow_passing_queen: true,
+            no_passing: false,
+            moon_rule: MoonRule::default(),
+            sun_rule: None,
+            jack_of_diamonds: false,
+            queen_breaks_hearts: false,
+            queen_allowed_first_trick: false,
+            rank_order: RankOrder::default(),
+            scoring: ScoringRules::default(),
+            auto_play_forced: false,
+            seed: None,
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct GameConfigBuilder {
+    config: GameConfig,
+}
+
+impl GameConfigBuilder {
+    pub fn player_count(mut self, player_count: usize) -> Self {
+        self.config.player_count = player_count;
+        self
+    }
+
+    pub fn target_score(mut self, target_score: i16) -> Self {
+        self.config.target_score = target_score;
+        self
+    }
+
+    pub fn pass_count(mut self, pass_count: usize) -> Self {
+        self.config.pass_count = pass_count;
+        self
+    }
+
+    pub fn allow_passing_queen(mut self, allow_passing_queen: bool) -> Self {
+        self.config.allow_passing_queen = allow_passing_queen;
+        self
+    }
+
+    pub fn no_passing(mut self, no_passing: bool) -> Self {
+        self.config.no_passing = no_passing;
+        self
+    }
+
+    pub fn moon_rule(mut self, moon_rule: MoonRule) -> Self {
+        self.config.moon_rule = moon_rule;
+        self
+    }
+
+    pub fn sun_rule(mut self, sun_rule: SunRule) -> Self {
+        self.config.sun_rule = Some(sun_rule);
+        self
+    }
+
+    pub fn jack_of_diamonds(mut self, jack_of_diamonds: bool) -> Self {
+        self.config.jack_of_diamonds = jack_of_diamonds;
+        self
+    }
+
+    pub fn queen_breaks_hearts(mut self, queen_breaks_hearts: bool) -> Self {
+        self.config.queen_breaks_hearts = queen_breaks_hearts;
+        self
+    }
+
+    pub fn queen_allowed_first_trick(mut self, queen_allowed_first_trick: bool) -> Self {
+        self.config.queen_allowed_first_trick = queen_allowed_first_trick;
+        self
+    }
+
+    pub fn rank_order(mut self, rank_order: RankOrder) -> Self {
+        self.config.rank_order = rank_order;
+        self
+    }
+
+    pub fn scoring(mut self, scoring: ScoringRules) -> Self {
+        self.config.scoring = scoring;
+        self
+    }
+
+    pub fn auto_play_forced(mut self, auto_play_forced: bool) -> Self {
+        self.config.auto_play_forced = auto_play_forced;
+        self
+    }
+
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.config.seed = Some(seed);
+        self
+    }
+
+    pub fn build(self) -> Result<GameConfig, ConfigError> {
+        if !(3..=6).contains(&self.config.player_count) {
+            return Err(ConfigError::PlayerCountError(self.config.player_count));
+        }
+        if self.config.target_score <= 0 {
+            return Err(ConfigError::TargetScoreError(self.config.target_score));
+        }
+        let hand_size = Card::all().len() / self.config.player_count;
+        if !(1..=hand_size).contains(&self.config.pass_count) {
+            return Err(ConfigError::PassCountError { pass_count: self.config.pass_count, hand_size });
+        }
+
+        Ok(self.config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{controller::RandomController, game::Game};
+
+    #[test]
+    fn games_follow_the_built_config() {
+        for player_count in 3..=6 {
+            let config = GameConfig::builder().player_count(player_count).target_score(50).build().unwrap();
+            let game = Game::new(config, RandomController::with_seed(0)).unwrap();
+
+            assert_eq!(game.players().len(), player_count);
+            assert_eq!(game.target_score(), 50);
+        }
+    }
+
+    #[test]
+    fn builder_rejects_invalid_settings() {
+        assert!(matches!(
+            GameConfig::builder().player_count(2).build(),
+            Err(ConfigError::PlayerCountError(2))
+        ));
+        assert!(matches!(
+            GameConfig::builder().player_count(7).build(),
+            Err(ConfigError::PlayerCountError(7))
+        ));
+        assert!(matches!(
+            GameConfig::builder().target_score(0).build(),
+            Err(ConfigError::TargetScoreError(0))
+        ));
+    }
+
+    #[test]
+    fn builder_limits_the_pass_count_to_the_hand_size() {
+        assert!(matches!(
+            GameConfig::builder().pass_count(0).build(),
+            Err(ConfigError::PassCountError { pass_count: 0, hand_size: 13 })
+        ));
+        assert!(matches!(
+            GameConfig::builder().player_count(6).pass_count(9).build(),
+            Err(ConfigError::PassCountError { pass_count: 9, hand_size: 8 })
+        ));
+        assert_eq!(GameConfig::builder().player_count(6).pass_count(8).build().unwrap().pass_count, 8);
+        assert_eq!(GameConfig::builder().player_count(3).pass_count(17).build().unwrap().pass_count, 17);
+    }
+
+    #[test]
+    fn scoring_rules_set_the_round_total() {
+        assert_eq!(ScoringRules::default().total_points(), 26);
+
+        let rules = ScoringRules { queen_points: 25, ..ScoringRules::default() };
+        assert_eq!(rules.total_points(), 38);
+        assert_eq!(ScoringRules { heart_points: 2, ..rules }.total_points(), 51);
+    }
+}
@@ -1,105 +1,1273 @@
-use inquire::{validator::ExactLengthValidator, MultiSelect, Select, Text};
+use std::{
+    cell::{Cell, Ref, RefCell},
+    collections::VecDeque,
+    fmt::Display,
+};
+
+use inquire::{
+    validator::{ExactLengthValidator, Validation},
+    CustomUserError, InquireError, MultiSelect, Select, Text,
+};
 use iter_tools::Itertools;
+use rand::{
+    rngs::StdRng,
+    seq::{IteratorRandom, SliceRandom},
+    SeedableRng,
+};
+use strum::IntoEnumIterator;
 use thiserror::Error;
 
-use super::{card::Card, game::{HeartsPlayedState, PassingOrder}, player::Player};
+use super::{
+    card::{Card, Rank, RankOrder, Suit, SuitOrder},
+    config::{GameConfig, MoonRule, SunRule},
+    events::GameEvent,
+    game::{MoonOption, PassingOrder},
+    observer::Observer,
+    player::{Player, PlayerId},
+};
 
 #[derive(Debug, Error)]
-#[error("Controller failure.")]
-pub struct ControllerError;
+pub enum ControllerError {
+    #[error("Controller failure.")]
+    Failed,
+    #[error("Cancelled by the player.")]
+    Cancelled,
+}
+
+impl From<InquireError> for ControllerError {
+    fn from(error: InquireError) -> Self {
+        match error {
+            InquireError::OperationCanceled | InquireError::OperationInterrupted => Self::Cancelled,
+            _ => Self::Failed,
+        }
+    }
+}
 
 type ControllerResult<T> = Result<T, ControllerError>;
 
-pub trait Controller {
-    fn get_names(&self) -> ControllerResult<Vec<String>>;
+pub trait Controller: Observer {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>>;
 
-    fn get_cards_to_pass(&self, from: &Player, to: &Player) -> ControllerResult<Vec<Card>>;
+    fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>>;
 
     fn get_card_to_place(
-        &self, player: &Player, table: &[(usize, Card)], is_first_move: bool,
-        hearts_played_state: &HeartsPlayedState,
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
     ) -> ControllerResult<Card>;
 
-    fn display_passing_order(&self, passing_order: &PassingOrder);
+    fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption>;
 
-    fn display_round_start(&self);
+    fn card_by_index(&self, player: &Player, index: usize) -> ControllerResult<Card> {
+        player.hand_indexed().get(index).map(|(_, card)| *card).ok_or(ControllerError::Failed)
+    }
+}
 
-    fn display_winner(&self, player: &Player, card: Card, score: u8);
+#[derive(Clone, Copy)]
+struct CardLabel {
+    card: Card,
+    colored: bool,
+}
 
-    fn display_scores(&self, players: &[Player]);
+impl Display for CardLabel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = if f.alternate() { format!("{:#}", self.card) } else { self.card.to_string() };
+        if self.colored && self.card.suit.is_red() {
+            write!(f, "\x1b[31m{name}\x1b[0m")
+        } else {
+            write!(f, "{name}")
+        }
+    }
 }
 
-pub struct CLIController;
+#[derive(Clone, Copy)]
+enum PlacementChoice {
+    Card(CardLabel),
+    ReviewLastTrick,
+}
+
+impl Display for PlacementChoice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Card(label) => write!(f, "{label}"),
+            Self::ReviewLastTrick => write!(f, "Review last trick"),
+        }
+    }
+}
+
+pub struct CLIController {
+    colored: bool,
+    hints: bool,
+    suit_order: SuitOrder,
+    rank_order: Cell<RankOrder>,
+    last_trick: RefCell<Option<String>>,
+}
+
+impl CLIController {
+    pub fn new(colored: bool) -> Self {
+        Self {
+            colored,
+            hints: false,
+            suit_order: SuitOrder::default(),
+            rank_order: Cell::new(RankOrder::default()),
+            last_trick: RefCell::new(None),
+        }
+    }
+
+    pub fn with_suit_order(mut self, suit_order: SuitOrder) -> Self {
+        self.suit_order = suit_order;
+        self
+    }
+
+    pub fn with_hints(mut self, hints: bool) -> Self {
+        self.hints = hints;
+        self
+    }
+
+    fn label(&self, card: Card) -> CardLabel {
+        CardLabel { card, colored: self.colored }
+    }
+}
 
 impl Controller for CLIController {
-    fn get_names(&self) -> ControllerResult<Vec<String>> {
-        (1..=4)
-            .map(|i| {
-                Text::new(&format!("Player {i}, enter your name:")).prompt().map_err(|_| ControllerError)
-            })
-            .collect()
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        let mut names: Vec<String> = vec![];
+        for i in 1..=count {
+            let taken_names = names.iter().map(|name| name.to_lowercase()).collect_vec();
+            let name = Text::new(&format!("Player {i}, enter your name:"))
+                .with_validator(move |input: &str| -> Result<Validation, CustomUserError> {
+                    let input = input.trim();
+                    if input.is_empty() {
+                        Ok(Validation::Invalid("Names cannot be empty.".into()))
+                    } else if taken_names.contains(&input.to_lowercase()) {
+                        Ok(Validation::Invalid("That name is already taken.".into()))
+                    } else {
+                        Ok(Validation::Valid)
+                    }
+                })
+                .prompt()
+                .map_err(ControllerError::from)?;
+            names.push(name.trim().to_owned());
+        }
+
+        Ok(names)
     }
 
-    fn get_cards_to_pass(&self, from: &Player, to: &Player) -> ControllerResult<Vec<Card>> {
+    fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        let options = from
+            .sorted_hand()
+            .into_iter()
+            .filter(|card| allow_queen || !card.is_queen_of_spades())
+            .map(|card| self.label(card))
+            .collect_vec();
+        let defaults = options.iter().positions(|label| suggested.contains(&label.card)).collect_vec();
         MultiSelect::new(
-            &format!("{}, select 3 cards to pass to {}.", &from.name, &to.name),
-            from.hand().iter().copied().sorted().collect(),
+            &format!(
+                "{}, select {count} cards to pass {} to {}.",
+                &from.name,
+                passing_order.direction(),
+                &to.name
+            ),
+            options,
         )
-        .with_validator(ExactLengthValidator::new(3))
+        .with_default(&defaults)
+        .with_validator(ExactLengthValidator::new(count))
         .with_page_size(13)
         .prompt()
-        .map_err(|_| ControllerError)
+        .map(|labels| labels.into_iter().map(|label| label.card).collect())
+        .map_err(ControllerError::from)
     }
 
     fn get_card_to_place(
-        &self, player: &Player, table: &[(usize, Card)], is_first_move: bool,
-        hearts_played_state: &HeartsPlayedState,
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
     ) -> ControllerResult<Card> {
-        let filtered_card_options = player
-            .hand()
-            .iter()
-            .filter(|card| !is_first_move || card.is_two_of_clubs())
-            .filter(|card| match table.first() {
-                Some((_, first_card)) => card.suit == first_card.suit,
-                None => true,
-            })
-            .filter(|card| match hearts_played_state {
-                HeartsPlayedState::NoHeartsPlayed => !card.is_hearts(),
-                _ => true,
-            })
-            .copied()
-            .sorted()
+        let options = player
+            .legal_moves(table, is_first_trick, hearts_broken, queen_allowed_first_trick)
+            .into_iter()
+            .sorted_by(|a, b| a.cmp_with_suit_order(b, self.suit_order))
             .collect_vec();
+        let suggestion = player
+            .suggested_card(
+                table,
+                is_first_trick,
+                hearts_broken,
+                queen_allowed_first_trick,
+                self.rank_order.get(),
+            )
+            .filter(|_| self.hints)
+            .and_then(|suggested| options.iter().position(|card| *card == suggested));
+        let mut choices =
+            options.into_iter().map(|card| PlacementChoice::Card(self.label(card))).collect_vec();
+        if self.last_trick.borrow().is_some() {
+            choices.push(PlacementChoice::ReviewLastTrick);
+        }
+
+        loop {
+            let choice = Select::new(&format!("{}, select a card.", &player.name), choices.clone())
+                .with_starting_cursor(suggestion.unwrap_or_default())
+                .with_page_size(14)
+                .prompt()
+                .map_err(ControllerError::from)?;
+            match choice {
+                PlacementChoice::Card(label) => return Ok(label.card),
+                PlacementChoice::ReviewLastTrick => {
+                    if let Some(last_trick) = self.last_trick.borrow().as_ref() {
+                        println!("\nLast trick: {last_trick}\n");
+                    }
+                }
+            }
+        }
+    }
 
+    fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
         Select::new(
-            &format!("{}, select a card.", &player.name),
-            if filtered_card_options.is_empty() {
-                player.hand().iter().copied().sorted().collect()
-            } else {
-                filtered_card_options
-            },
+            &format!("{shooter}, you shot the moon! Choose how to score it."),
+            MoonOption::iter().collect(),
         )
-        .with_page_size(13)
         .prompt()
-        .map_err(|_| ControllerError)
+        .map_err(ControllerError::from)
+    }
+}
+
+impl Observer for CLIController {
+    fn display_game_start(&self, config: &GameConfig, players: &[Player]) {
+        self.rank_order.set(config.rank_order);
+        println!("Players: {}", players.iter().join(", "));
+        println!("Playing to {} points.", config.target_score);
+
+        let variants = [
+            (config.jack_of_diamonds, "Jack of Diamonds"),
+            (config.queen_breaks_hearts, "Queen breaks hearts"),
+            (!config.allow_passing_queen, "No passing the Queen"),
+            (config.no_passing, "No passing"),
+            (config.sun_rule.is_some(), "Shoot the sun"),
+            (config.rank_order == RankOrder::AceLow, "Ace low"),
+            (config.queen_allowed_first_trick, "Queen allowed on the first trick"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+        .join(", ");
+        if !variants.is_empty() {
+            println!("Variants: {variants}");
+        }
     }
 
-    fn display_round_start(&self) {
-        println!();
+    fn display_round_start(&self, round: usize) {
+        self.last_trick.replace(None);
+        println!("{:-^20}\n", format!("Round {round}"));
     }
 
-    fn display_passing_order(&self, passing_order: &PassingOrder) {
-        println!("\nPassing order: {passing_order}\n");
+    fn display_pass_preview(&self, player: &Player) {
+        self.display_hand(player);
     }
 
-    fn display_winner(&self, player: &Player, card: Card, score: u8) {
-        println!("\n{player} wins this trick with the {card} for {score} points.\n");
+    fn display_received_cards(&self, player: &Player, received: &[Card]) {
+        let cards = received
+            .iter()
+            .sorted_by(|a, b| a.cmp_with_suit_order(b, self.suit_order))
+            .map(|card| self.label(*card))
+            .join(", ");
+        println!("{player} received: {cards}\n");
+    }
+
+    fn display_hand(&self, player: &Player) {
+        println!("{player}'s hand:");
+        for suit in self.suit_order.suits() {
+            let cards = player
+                .cards_of_suit(suit)
+                .into_iter()
+                .sorted()
+                .map(|card| format!("{:#}", self.label(card)))
+                .join(" ");
+            println!("  {suit}: {cards}");
+        }
+    }
+
+    fn display_forced_move(&self, player: &Player, card: Card) {
+        println!("{player} has only one legal move and plays the {}.\n", self.label(card));
+    }
+
+    fn display_table(&self, players: &[Player], table: &[(PlayerId, Card)]) {
+        if !table.is_empty() {
+            println!(
+                "{}",
+                table
+                    .iter()
+                    .map(|(i, card)| format!("{} played {:#}", players[i.0], self.label(*card)))
+                    .join(", ")
+            );
+        }
+    }
+
+    fn display_passing_order(&self, passing_order: &PassingOrder, players: &[Player]) {
+        println!("\nPassing order: {}\n", passing_order.describe(players));
+    }
+
+    fn display_last_trick(&self, players: &[Player], trick: &[(PlayerId, Card)], winner: &Player) {
+        let plays = trick
+            .iter()
+            .map(|(i, card)| format!("{} played {:#}", players[i.0], self.label(*card)))
+            .join(", ");
+        self.last_trick.replace(Some(format!("{plays}; {winner} won")));
+    }
+
+    fn display_winner(&self, player: &Player, card: Card, score: i16) {
+        println!("\n{player} wins this trick with the {} for {score} points.\n", self.label(card));
+    }
+
+    fn display_moon_shot(&self, shooter: &Player, rule: &MoonRule) {
+        match rule {
+            MoonRule::AddToOthers => {
+                println!("{shooter} shot the moon! Everyone else takes the round's points.\n")
+            }
+            MoonRule::SubtractFromSelf => {
+                println!("{shooter} shot the moon! They lose the round's points.\n")
+            }
+            MoonRule::PlayerChoice => println!("{shooter} shot the moon!\n"),
+        }
+    }
+
+    fn display_sun_shot(&self, shooter: &Player, rule: &SunRule, points: i16) {
+        match rule {
+            SunRule::AddToOthers => {
+                println!("{shooter} shot the sun! Everyone else takes {points} points.\n")
+            }
+            SunRule::SubtractFromSelf => println!("{shooter} shot the sun! They lose {points} points.\n"),
+        }
     }
 
     fn display_scores(&self, players: &[Player]) {
         println!("{:-^20}", "Scores");
-        for player in players.iter().sorted() {
+        for player in players.iter().sorted_by_key(|player| player.score()) {
             println!("{player}: {} points", player.score());
         }
     }
+
+    fn display_game_over(&self, winners: &[&Player]) {
+        println!("\n{:-^20}", "Game Over");
+        match winners {
+            [winner] => println!("{winner} wins the game!"),
+            _ => println!("{} tie for the win!", winners.iter().join(" and ")),
+        }
+    }
+
+    fn display_match_standings(&self, names: &[String], wins: &[usize]) {
+        println!("\n{:-^20}", "Match Standings");
+        for (name, wins) in names.iter().zip(wins).sorted_by_key(|(_, wins)| std::cmp::Reverse(**wins)) {
+            println!("{name}: {wins}");
+        }
+    }
+}
+
+pub struct ScriptedController {
+    names: Vec<String>,
+    passes: RefCell<VecDeque<Vec<Card>>>,
+    placements: RefCell<VecDeque<Card>>,
+    moon_options: RefCell<VecDeque<MoonOption>>,
+}
+
+impl ScriptedController {
+    pub fn new(names: Vec<String>, passes: Vec<Vec<Card>>, placements: Vec<Card>) -> Self {
+        Self {
+            names,
+            passes: RefCell::new(passes.into()),
+            placements: RefCell::new(placements.into()),
+            moon_options: RefCell::new(VecDeque::new()),
+        }
+    }
+
+    pub fn with_moon_options(self, moon_options: Vec<MoonOption>) -> Self {
+        Self { moon_options: RefCell::new(moon_options.into()), ..self }
+    }
+}
+
+impl Controller for ScriptedController {
+    fn get_names(&self, _count: usize) -> ControllerResult<Vec<String>> {
+        Ok(self.names.clone())
+    }
+
+    fn get_cards_to_pass(
+        &self, _from: &Player, _to: &Player, _passing_order: &PassingOrder, _count: usize,
+        _allow_queen: bool, _suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        self.passes.borrow_mut().pop_front().ok_or(ControllerError::Failed)
+    }
+
+    fn get_card_to_place(
+        &self, _player: &Player, _table: &[(PlayerId, Card)], _is_first_trick: bool, _hearts_broken: bool,
+        _queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        self.placements.borrow_mut().pop_front().ok_or(ControllerError::Failed)
+    }
+
+    fn choose_moon_option(&self, _shooter: &Player) -> ControllerResult<MoonOption> {
+        self.moon_options.borrow_mut().pop_front().ok_or(ControllerError::Failed)
+    }
+}
+
+impl Observer for ScriptedController {}
+
+pub struct RandomController {
+    rng: RefCell<StdRng>,
+}
+
+impl RandomController {
+    pub fn new() -> Self {
+        Self {
+            rng: RefCell::new(
+                StdRng::from_rng(rand::thread_rng())
+                    .expect("Thread RNG should be able to seed the controller"),
+            ),
+        }
+    }
+
+    pub fn with_seed(seed: u64) -> Self {
+        Self { rng: RefCell::new(StdRng::seed_from_u64(seed)) }
+    }
+}
+
+impl Default for RandomController {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Controller for RandomController {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        Ok((1..=count).map(|i| format!("Player {i}")).collect())
+    }
+
+    fn get_cards_to_pass(
+        &self, from: &Player, _to: &Player, _passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        _suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        Ok(from
+            .hand()
+            .iter()
+            .filter(|card| allow_queen || !card.is_queen_of_spades())
+            .copied()
+            .choose_multiple(&mut *self.rng.borrow_mut(), count))
+    }
+
+    fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        player
+            .legal_moves(table, is_first_trick, hearts_broken, queen_allowed_first_trick)
+            .choose(&mut *self.rng.borrow_mut())
+            .copied()
+            .ok_or(ControllerError::Failed)
+    }
+
+    fn choose_moon_option(&self, _shooter: &Player) -> ControllerResult<MoonOption> {
+        MoonOption::iter().choose(&mut *self.rng.borrow_mut()).ok_or(ControllerError::Failed)
+    }
+}
+
+impl Observer for RandomController {}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AiDifficulty {
+    Easy,
+    #[default]
+    Medium,
+    /// Avoids leading suits an opponent has shown void in and drops the Queen onto a higher spade.
+    Hard,
+}
+
+pub struct AIController {
+    difficulty: AiDifficulty,
+    known_voids: RefCell<Vec<(PlayerId, Suit)>>,
+    rank_order: Cell<RankOrder>,
+    rng: RefCell<StdRng>,
+}
+
+impl AIController {
+    pub fn new(difficulty: AiDifficulty) -> Self {
+        Self {
+            difficulty,
+            known_voids: RefCell::new(vec![]),
+            rank_order: Cell::new(RankOrder::default()),
+            rng: RefCell::new(
+                StdRng::from_rng(rand::thread_rng())
+                    .expect("Thread RNG should be able to seed the controller"),
+            ),
+        }
+    }
+
+    pub fn with_seed(difficulty: AiDifficulty, seed: u64) -> Self {
+        Self {
+            difficulty,
+            known_voids: RefCell::new(vec![]),
+            rank_order: Cell::new(RankOrder::default()),
+            rng: RefCell::new(StdRng::seed_from_u64(seed)),
+        }
+    }
+
+    fn observe(&self, table: &[(PlayerId, Card)]) {
+        let Some((_, led_card)) = table.first() else {
+            return;
+        };
+
+        let mut known_voids = self.known_voids.borrow_mut();
+        for (i, card) in table.iter().filter(|(_, card)| card.suit != led_card.suit) {
+            if !known_voids.contains(&(*i, led_card.suit)) {
+                known_voids.push((*i, led_card.suit));
+            }
+        }
+    }
+
+    fn value(&self, card: &Card) -> u8 {
+        self.rank_order.get().value(card.rank)
+    }
+
+    fn beats(&self, card: &Card, other: &Card, led_suit: Suit) -> bool {
+        card.beats_with(other, led_suit, self.rank_order.get())
+    }
+
+    fn choose_lead(&self, player_id: PlayerId, moves: &[Card]) -> Option<Card> {
+        if self.difficulty == AiDifficulty::Hard {
+            let known_voids = self.known_voids.borrow();
+            let safe_lead = moves
+                .iter()
+                .copied()
+                .filter(|card| known_voids.iter().all(|(id, suit)| *id == player_id || *suit != card.suit))
+                .min_by_key(|card| self.value(card));
+            if safe_lead.is_some() {
+                return safe_lead;
+            }
+        }
+
+        moves.iter().copied().min_by_key(|card| self.value(card))
+    }
+
+    fn pass_priority(card: &Card) -> u8 {
+        let rank = card.rank.value();
+        match (card.rank, card.suit) {
+            (Rank::Queen, Suit::Spades) => 50,
+            (Rank::Ace | Rank::King, Suit::Spades) => 30 + rank,
+            (_, Suit::Hearts) => 15 + rank,
+            _ => rank,
+        }
+    }
+
+    fn choose_card(&self, player_id: PlayerId, moves: &[Card], table: &[(PlayerId, Card)]) -> Option<Card> {
+        let Some((_, led_card)) = table.first() else {
+            return self.choose_lead(player_id, moves);
+        };
+
+        if moves.iter().all(|card| card.suit == led_card.suit) {
+            let winning_card = table
+                .iter()
+                .map(|(_, card)| *card)
+                .reduce(
+                    |winner, challenger| {
+                        if self.beats(&challenger, &winner, led_card.suit) {
+                            challenger
+                        } else {
+                            winner
+                        }
+                    },
+                )
+                .expect("Table should contain the led card");
+
+            let queen = moves.iter().copied().find(Card::is_queen_of_spades);
+            if let Some(queen) = queen.filter(|queen| {
+                self.difficulty == AiDifficulty::Hard && self.beats(&winning_card, queen, led_card.suit)
+            }) {
+                return Some(queen);
+            }
+
+            moves
+                .iter()
+                .copied()
+                .filter(|card| !self.beats(card, &winning_card, led_card.suit))
+                .min_by_key(|card| self.value(card))
+                .or_else(|| {
+                    moves
+                        .iter()
+                        .copied()
+                        .filter(|card| !card.is_queen_of_spades())
+                        .min_by_key(|card| self.value(card))
+                        .or(moves.first().copied())
+                })
+        } else {
+            moves
+                .iter()
+                .copied()
+                .find(Card::is_queen_of_spades)
+                .or_else(|| moves.iter().copied().filter(Card::is_hearts).max_by_key(|card| self.value(card)))
+                .or_else(|| moves.iter().copied().max_by_key(|card| self.value(card)))
+        }
+    }
+}
+
+impl Controller for AIController {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        Ok((1..=count).map(|i| format!("CPU {i}")).collect())
+    }
+
+    fn get_cards_to_pass(
+        &self, from: &Player, _to: &Player, _passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        _suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        Ok(from
+            .hand()
+            .iter()
+            .filter(|card| allow_queen || !card.is_queen_of_spades())
+            .copied()
+            .sorted_by_key(Self::pass_priority)
+            .rev()
+            .take(count)
+            .collect())
+    }
+
+    fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        let moves = player.legal_moves(table, is_first_trick, hearts_broken, queen_allowed_first_trick);
+        self.observe(table);
+
+        let card_choice = match self.difficulty {
+            AiDifficulty::Easy => moves.choose(&mut *self.rng.borrow_mut()).copied(),
+            AiDifficulty::Medium | AiDifficulty::Hard => self.choose_card(player.id(), &moves, table),
+        };
+
+        card_choice.ok_or(ControllerError::Failed)
+    }
+
+    fn choose_moon_option(&self, _shooter: &Player) -> ControllerResult<MoonOption> {
+        Ok(MoonOption::AddToOthers)
+    }
+}
+
+impl Observer for AIController {
+    fn display_game_start(&self, config: &GameConfig, _players: &[Player]) {
+        self.rank_order.set(config.rank_order);
+    }
+
+    fn display_round_start(&self, _round: usize) {
+        self.known_voids.borrow_mut().clear();
+    }
+
+    fn display_last_trick(&self, _players: &[Player], trick: &[(PlayerId, Card)], _winner: &Player) {
+        self.observe(trick);
+    }
+}
+
+pub struct LoggingController<C: Controller> {
+    inner: C,
+    events: RefCell<Vec<GameEvent>>,
+}
+
+impl<C: Controller> LoggingController<C> {
+    pub fn new(inner: C) -> Self {
+        Self { inner, events: RefCell::new(vec![]) }
+    }
+
+    pub fn events(&self) -> Ref<Vec<GameEvent>> {
+        self.events.borrow()
+    }
+
+    pub fn into_inner(self) -> C {
+        self.inner
+    }
+
+    fn record(&self, event: GameEvent) {
+        self.events.borrow_mut().push(event);
+    }
+}
+
+impl<C: Controller> Controller for LoggingController<C> {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        let names = self.inner.get_names(count)?;
+        self.record(GameEvent::NamesChosen(names.clone()));
+        Ok(names)
+    }
+
+    fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        let cards = self.inner.get_cards_to_pass(from, to, passing_order, count, allow_queen, suggested)?;
+        self.record(GameEvent::CardsPassed {
+            from: from.name.clone(),
+            to: to.name.clone(),
+            cards: cards.clone(),
+        });
+        Ok(cards)
+    }
+
+    fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        let card = self.inner.get_card_to_place(
+            player,
+            table,
+            is_first_trick,
+            hearts_broken,
+            queen_allowed_first_trick,
+        )?;
+        self.record(GameEvent::CardPlaced { player: player.name.clone(), card });
+        Ok(card)
+    }
+
+    fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
+        let option = self.inner.choose_moon_option(shooter)?;
+        self.record(GameEvent::MoonOptionChosen { player: shooter.name.clone(), option });
+        Ok(option)
+    }
+}
+
+impl<C: Controller> Observer for LoggingController<C> {
+    fn on_event(&self, event: &GameEvent) {
+        if matches!(
+            event,
+            GameEvent::TrickWon { .. }
+                | GameEvent::RoundScored { .. }
+                | GameEvent::MoonShot { .. }
+                | GameEvent::GameOver { .. }
+        ) {
+            self.record(event.clone());
+        }
+        self.inner.on_event(event);
+    }
+
+    fn display_game_start(&self, config: &GameConfig, players: &[Player]) {
+        self.inner.display_game_start(config, players);
+    }
+
+    fn display_passing_order(&self, passing_order: &PassingOrder, players: &[Player]) {
+        self.inner.display_passing_order(passing_order, players);
+    }
+
+    fn display_round_start(&self, round: usize) {
+        self.inner.display_round_start(round);
+    }
+
+    fn display_pass_preview(&self, player: &Player) {
+        self.inner.display_pass_preview(player);
+    }
+
+    fn display_received_cards(&self, player: &Player, received: &[Card]) {
+        self.inner.display_received_cards(player, received);
+    }
+
+    fn display_hand(&self, player: &Player) {
+        self.inner.display_hand(player);
+    }
+
+    fn display_forced_move(&self, player: &Player, card: Card) {
+        self.inner.display_forced_move(player, card);
+    }
+
+    fn display_last_trick(&self, players: &[Player], trick: &[(PlayerId, Card)], winner: &Player) {
+        self.inner.display_last_trick(players, trick, winner);
+    }
+
+    fn display_table(&self, players: &[Player], table: &[(PlayerId, Card)]) {
+        self.inner.display_table(players, table);
+    }
+
+    fn display_winner(&self, player: &Player, card: Card, score: i16) {
+        self.inner.display_winner(player, card, score);
+    }
+
+    fn display_moon_shot(&self, shooter: &Player, rule: &MoonRule) {
+        self.inner.display_moon_shot(shooter, rule);
+    }
+
+    fn display_sun_shot(&self, shooter: &Player, rule: &SunRule, points: i16) {
+        self.inner.display_sun_shot(shooter, rule, points);
+    }
+
+    fn display_scores(&self, players: &[Player]) {
+        self.inner.display_scores(players);
+    }
+
+    fn display_game_over(&self, winners: &[&Player]) {
+        self.inner.display_game_over(winners);
+    }
+
+    fn display_match_standings(&self, names: &[String], wins: &[usize]) {
+        self.inner.display_match_standings(names, wins);
+    }
+}
+
+pub struct ReplayController {
+    script: ScriptedController,
+}
+
+impl ReplayController {
+    pub fn new(events: &[GameEvent]) -> Self {
+        let mut names = vec![];
+        let mut passes = vec![];
+        let mut placements = vec![];
+        let mut moon_options = vec![];
+        for event in events {
+            match event {
+                GameEvent::NamesChosen(chosen_names) => names = chosen_names.clone(),
+                GameEvent::CardsPassed { cards, .. } => passes.push(cards.clone()),
+                GameEvent::CardPlaced { card, .. } => placements.push(*card),
+                GameEvent::MoonOptionChosen { option, .. } => moon_options.push(*option),
+                _ => {}
+            }
+        }
+
+        Self { script: ScriptedController::new(names, passes, placements).with_moon_options(moon_options) }
+    }
+}
+
+impl Controller for ReplayController {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        self.script.get_names(count)
+    }
+
+    fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        self.script.get_cards_to_pass(from, to, passing_order, count, allow_queen, suggested)
+    }
+
+    fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        self.script.get_card_to_place(player, table, is_first_trick, hearts_broken, queen_allowed_first_trick)
+    }
+
+    fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
+        self.script.choose_moon_option(shooter)
+    }
+}
+
+impl Observer for ReplayController {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        card,
+        models::{
+            deck::{
+                fixtures::{suited_deck, suited_tricks},
+                Deck,
+            },
+            game::{Game, GameError},
+        },
+    };
+
+    fn player(id: usize, hand: Vec<Card>) -> Player {
+        Player::restore(PlayerId(id), format!("Player {}", id + 1), hand, 0, vec![])
+    }
+
+    struct Seats(Vec<Box<dyn Controller>>);
+
+    impl Seats {
+        fn seat(&self, player: &Player) -> &dyn Controller {
+            self.0[player.id().0].as_ref()
+        }
+    }
+
+    impl Controller for Seats {
+        fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+            Ok((1..=count).map(|i| format!("Player {i}")).collect())
+        }
+
+        fn get_cards_to_pass(
+            &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+            suggested: &[Card],
+        ) -> ControllerResult<Vec<Card>> {
+            self.seat(from).get_cards_to_pass(from, to, passing_order, count, allow_queen, suggested)
+        }
+
+        fn get_card_to_place(
+            &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+            queen_allowed_first_trick: bool,
+        ) -> ControllerResult<Card> {
+            self.seat(player).get_card_to_place(
+                player,
+                table,
+                is_first_trick,
+                hearts_broken,
+                queen_allowed_first_trick,
+            )
+        }
+
+        fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
+            self.seat(shooter).choose_moon_option(shooter)
+        }
+    }
+
+    impl Observer for Seats {
+        fn display_game_start(&self, config: &GameConfig, players: &[Player]) {
+            for seat in &self.0 {
+                seat.display_game_start(config, players);
+            }
+        }
+
+        fn display_round_start(&self, round: usize) {
+            for seat in &self.0 {
+                seat.display_round_start(round);
+            }
+        }
+
+        fn display_last_trick(&self, players: &[Player], trick: &[(PlayerId, Card)], winner: &Player) {
+            for seat in &self.0 {
+                seat.display_last_trick(players, trick, winner);
+            }
+        }
+    }
+
+    #[test]
+    fn scripted_controller_replays_answers_in_order() {
+        let names = vec!["Ann".to_owned(), "Bo".to_owned(), "Cy".to_owned()];
+        let controller = ScriptedController::new(
+            names.clone(),
+            vec![vec![card!(2, C), card!(3, C), card!(4, C)]],
+            vec![card!(Q, S), card!(A, H)],
+        )
+        .with_moon_options(vec![MoonOption::SubtractFromSelf]);
+        let (from, to) = (player(0, vec![]), player(1, vec![]));
+
+        assert_eq!(controller.get_names(3).unwrap(), names);
+        assert_eq!(
+            controller.get_cards_to_pass(&from, &to, &PassingOrder::Left, 3, true, &[]).unwrap(),
+            vec![card!(2, C), card!(3, C), card!(4, C)]
+        );
+        assert_eq!(controller.get_card_to_place(&from, &[], false, false, false).unwrap(), card!(Q, S));
+        assert_eq!(controller.get_card_to_place(&from, &[], false, false, false).unwrap(), card!(A, H));
+        assert_eq!(controller.choose_moon_option(&from).unwrap(), MoonOption::SubtractFromSelf);
+    }
+
+    #[test]
+    fn scripted_controller_fails_when_exhausted() {
+        let controller = ScriptedController::new(vec![], vec![], vec![]);
+        let (from, to) = (player(0, vec![]), player(1, vec![]));
+
+        assert!(matches!(
+            controller.get_cards_to_pass(&from, &to, &PassingOrder::Left, 3, true, &[]),
+            Err(ControllerError::Failed)
+        ));
+        assert!(matches!(
+            controller.get_card_to_place(&from, &[], false, false, false),
+            Err(ControllerError::Failed)
+        ));
+        assert!(matches!(controller.choose_moon_option(&from), Err(ControllerError::Failed)));
+    }
+
+    #[test]
+    fn colored_labels_wrap_red_cards_in_both_formats() {
+        let colored = CLIController::new(true);
+        let plain = CLIController::new(false);
+
+        assert_eq!(colored.label(card!(Q, H)).to_string(), format!("\x1b[31m{}\x1b[0m", card!(Q, H)));
+        assert_eq!(
+            format!("{:#}", colored.label(card!(10, D))),
+            format!("\x1b[31m{:#}\x1b[0m", card!(10, D))
+        );
+        assert_eq!(colored.label(card!(Q, S)).to_string(), card!(Q, S).to_string());
+        assert_eq!(format!("{:#}", plain.label(card!(Q, H))), format!("{:#}", card!(Q, H)));
+    }
+
+    #[test]
+    fn random_controllers_finish_a_seeded_game() {
+        let config = GameConfig::builder().seed(10).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(10)).unwrap();
+
+        let outcome = game.play().unwrap();
+
+        assert!(outcome.scores.iter().any(|score| *score >= 100));
+        assert!(!outcome.winners.is_empty());
+    }
+
+    #[test]
+    fn ai_controllers_finish_a_game_against_a_scripted_player() {
+        // The scripted player holds every heart, so any order it plays them in is legal and it never
+        // wins a trick (and therefore never leads).
+        let hearts = Card::all().into_iter().filter(Card::is_hearts).collect_vec();
+        let others = Card::all()
+            .into_iter()
+            .filter(|card| !card.is_hearts())
+            .sorted_by(Card::cmp_by_suit_then_rank)
+            .collect_vec();
+        let stacked =
+            (0..3).flat_map(|seat| others.iter().skip(seat).step_by(3).copied()).chain(hearts.clone());
+        let script = ScriptedController::new(vec![], vec![], hearts.repeat(20));
+        let mut seats: Vec<Box<dyn Controller>> = vec![];
+        for _ in 0..3 {
+            seats.push(Box::new(AIController::new(AiDifficulty::Medium)));
+        }
+        seats.push(Box::new(script));
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = Game::new(config, Seats(seats)).unwrap().with_deck(Deck::stacked(stacked.collect()));
+
+        let outcome = game.play().unwrap();
+
+        assert!(game.is_over());
+        assert!(outcome.scores.iter().any(|score| *score >= 100));
+        assert!(!outcome.winners.is_empty());
+    }
+
+    #[test]
+    fn ai_ducks_under_the_winning_card() {
+        let ai = AIController::with_seed(AiDifficulty::Medium, 27);
+        let table = [(PlayerId(1), card!(10, C)), (PlayerId(2), card!(Q, C))];
+
+        let ducking = player(3, vec![card!(3, D), card!(J, C), card!(K, C), card!(A, C)]);
+        assert_eq!(ai.get_card_to_place(&ducking, &table, false, true, false).unwrap(), card!(J, C));
+
+        let forced_over = player(3, vec![card!(3, D), card!(K, C), card!(A, C)]);
+        assert_eq!(ai.get_card_to_place(&forced_over, &table, false, true, false).unwrap(), card!(K, C));
+    }
+
+    #[test]
+    fn ai_ducks_with_a_low_ace_when_aces_are_low() {
+        let ai = AIController::with_seed(AiDifficulty::Medium, 71);
+        let config = GameConfig::builder().rank_order(RankOrder::AceLow).build().unwrap();
+        ai.display_game_start(&config, &[]);
+        let table = [(PlayerId(1), card!(10, C)), (PlayerId(2), card!(Q, C))];
+
+        let hand = player(3, vec![card!(3, D), card!(K, C), card!(A, C)]);
+        assert_eq!(ai.get_card_to_place(&hand, &table, false, true, false).unwrap(), card!(A, C));
+
+        let void = player(3, vec![card!(A, H), card!(K, H), card!(2, D)]);
+        assert_eq!(ai.get_card_to_place(&void, &table, false, true, false).unwrap(), card!(K, H));
+    }
+
+    #[test]
+    fn ai_dumps_the_queen_when_void() {
+        let ai = AIController::with_seed(AiDifficulty::Medium, 27);
+        let table = [(PlayerId(1), card!(5, D))];
+
+        let with_queen = player(2, vec![card!(2, C), card!(A, H), card!(Q, S), card!(K, C)]);
+        assert_eq!(ai.get_card_to_place(&with_queen, &table, false, true, false).unwrap(), card!(Q, S));
+
+        let without_queen = player(2, vec![card!(2, C), card!(3, H), card!(A, H), card!(K, C)]);
+        assert_eq!(ai.get_card_to_place(&without_queen, &table, false, true, false).unwrap(), card!(A, H));
+    }
+
+    #[test]
+    fn ai_passes_the_dangerous_spades_first() {
+        let ai = AIController::with_seed(AiDifficulty::Medium, 27);
+        let hand = vec![card!(A, H), card!(K, S), card!(2, C), card!(Q, S), card!(A, D), card!(A, S)];
+        let (from, to) = (player(0, hand), player(1, vec![]));
+
+        assert_eq!(
+            ai.get_cards_to_pass(&from, &to, &PassingOrder::Left, 3, true, &[]).unwrap(),
+            vec![card!(Q, S), card!(A, S), card!(K, S)]
+        );
+        assert_eq!(
+            ai.get_cards_to_pass(&from, &to, &PassingOrder::Left, 3, false, &[]).unwrap(),
+            vec![card!(A, S), card!(K, S), card!(A, H)]
+        );
+    }
+
+    #[test]
+    fn hard_ai_beats_easy_ai_on_average() {
+        let (mut hard_total, mut easy_total) = (0, 0);
+        for seed in 0..40 {
+            let mut seats: Vec<Box<dyn Controller>> = vec![];
+            for seat in 0..2 {
+                seats.push(Box::new(AIController::with_seed(AiDifficulty::Hard, seed * 4 + seat * 2)));
+                seats.push(Box::new(AIController::with_seed(AiDifficulty::Easy, seed * 4 + seat * 2 + 1)));
+            }
+            let config = GameConfig::builder().seed(seed).build().unwrap();
+            let outcome = Game::new(config, Seats(seats)).unwrap().play().unwrap();
+
+            hard_total += i32::from(outcome.scores[0]) + i32::from(outcome.scores[2]);
+            easy_total += i32::from(outcome.scores[1]) + i32::from(outcome.scores[3]);
+        }
+
+        assert!(hard_total < easy_total, "Hard scored {hard_total}, Easy scored {easy_total}");
+    }
+
+    #[test]
+    fn hard_ai_drops_the_queen_onto_a_higher_spade() {
+        let table = [(PlayerId(1), card!(3, S)), (PlayerId(2), card!(K, S))];
+        let hand = vec![card!(2, S), card!(Q, S), card!(5, S)];
+
+        let hard = AIController::with_seed(AiDifficulty::Hard, 28);
+        assert_eq!(
+            hard.get_card_to_place(&player(3, hand.clone()), &table, false, true, false).unwrap(),
+            card!(Q, S)
+        );
+
+        let medium = AIController::with_seed(AiDifficulty::Medium, 28);
+        assert_eq!(
+            medium.get_card_to_place(&player(3, hand), &table, false, true, false).unwrap(),
+            card!(2, S)
+        );
+    }
+
+    #[test]
+    fn hard_ai_avoids_leading_an_opponents_void() {
+        let players = (0..4).map(|id| player(id, vec![])).collect_vec();
+        let trick = [
+            (PlayerId(0), card!(3, C)),
+            (PlayerId(1), card!(5, C)),
+            (PlayerId(2), card!(8, H)),
+            (PlayerId(3), card!(9, C)),
+        ];
+        let hand = vec![card!(2, C), card!(9, D)];
+
+        let hard = AIController::with_seed(AiDifficulty::Hard, 28);
+        hard.display_last_trick(&players, &trick, &players[3]);
+        assert_eq!(
+            hard.get_card_to_place(&player(0, hand.clone()), &[], false, true, false).unwrap(),
+            card!(9, D)
+        );
+
+        hard.display_round_start(2);
+        assert_eq!(hard.get_card_to_place(&player(0, hand), &[], false, true, false).unwrap(), card!(2, C));
+    }
+
+    #[test]
+    fn logging_controller_records_a_scripted_round() {
+        let names = (1..=4).map(|i| format!("Player {i}")).collect_vec();
+        let tricks = suited_tricks();
+        let placements = tricks.iter().flatten().copied().collect();
+        let controller = LoggingController::new(ScriptedController::new(names.clone(), vec![], placements));
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = Game::new(config, controller).unwrap().with_deck(suited_deck());
+
+        game.round().unwrap();
+
+        let mut expected = vec![GameEvent::NamesChosen(names.clone())];
+        for trick in &tricks {
+            for (name, card) in names.iter().zip(trick) {
+                expected.push(GameEvent::CardPlaced { player: name.clone(), card: *card });
+            }
+            expected.push(GameEvent::TrickWon {
+                player: names[0].clone(),
+                card: trick[0],
+                score: trick.iter().map(|card| i16::from(card.score())).sum(),
+            });
+        }
+        expected.push(GameEvent::MoonShot { player: names[0].clone(), option: MoonOption::AddToOthers });
+        expected.push(GameEvent::RoundScored {
+            round: 1,
+            scores: names.iter().cloned().zip([0, 26, 26, 26]).collect(),
+        });
+        assert_eq!(*game.controller().events(), expected);
+    }
+
+    #[test]
+    fn replaying_a_recorded_game_reproduces_its_scores() {
+        let config = GameConfig::builder().seed(32).build().unwrap();
+        let mut recorded =
+            Game::new(config, LoggingController::new(RandomController::with_seed(32))).unwrap();
+        let original = recorded.play().unwrap();
+
+        let replay = ReplayController::new(&recorded.controller().events());
+        let replayed = Game::new(config, replay).unwrap().play().unwrap();
+
+        assert_eq!(replayed.scores, original.scores);
+        assert_eq!(replayed, original);
+    }
+
+    #[test]
+    fn controllers_name_the_requested_number_of_players() {
+        assert_eq!(
+            RandomController::with_seed(0).get_names(3).unwrap(),
+            vec!["Player 1", "Player 2", "Player 3"]
+        );
+        assert_eq!(
+            AIController::new(AiDifficulty::Easy).get_names(3).unwrap(),
+            vec!["CPU 1", "CPU 2", "CPU 3"]
+        );
+
+        let config = GameConfig::builder().player_count(3).build().unwrap();
+        let game = Game::new(config, RandomController::with_seed(0)).unwrap();
+        assert_eq!(game.players().len(), 3);
+    }
+
+    #[test]
+    fn card_index_maps_back_to_the_placed_card() {
+        let player = player(0, vec![card!(K, H), card!(2, S), card!(9, C), card!(A, D)]);
+        let controller = RandomController::with_seed(0);
+
+        for (index, card) in player.hand_indexed() {
+            let chosen = controller.card_by_index(&player, index).unwrap();
+            assert_eq!(chosen, card);
+            assert_eq!(player.place(&chosen), Some(card));
+            player.undo_place(card);
+        }
+        assert!(matches!(controller.card_by_index(&player, 4), Err(ControllerError::Failed)));
+    }
+
+    #[test]
+    fn cancelled_prompts_are_not_failures() {
+        assert!(matches!(ControllerError::from(InquireError::OperationCanceled), ControllerError::Cancelled));
+        assert!(matches!(
+            ControllerError::from(InquireError::OperationInterrupted),
+            ControllerError::Cancelled
+        ));
+        assert!(matches!(ControllerError::from(InquireError::NotTTY), ControllerError::Failed));
+
+        assert!(GameError::TurnError(ControllerError::Cancelled).is_cancelled());
+        assert!(!GameError::TurnError(ControllerError::Failed).is_cancelled());
+    }
+
+    #[derive(Default)]
+    struct PassRecorder {
+        inner: RandomController,
+        passes: RefCell<Vec<(PlayerId, PlayerId, &'static str)>>,
+    }
+
+    impl Controller for PassRecorder {
+        fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+            self.inner.get_names(count)
+        }
+
+        fn get_cards_to_pass(
+            &self, from: &Player, to: &Player, passing_order: &PassingOrder, _count: usize,
+            _allow_queen: bool, suggested: &[Card],
+        ) -> ControllerResult<Vec<Card>> {
+            self.passes.borrow_mut().push((from.id(), to.id(), passing_order.direction()));
+            Ok(suggested.to_vec())
+        }
+
+        fn get_card_to_place(
+            &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+            queen_allowed_first_trick: bool,
+        ) -> ControllerResult<Card> {
+            self.inner.get_card_to_place(
+                player,
+                table,
+                is_first_trick,
+                hearts_broken,
+                queen_allowed_first_trick,
+            )
+        }
+
+        fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
+            self.inner.choose_moon_option(shooter)
+        }
+    }
+
+    impl Observer for PassRecorder {}
+
+    #[test]
+    fn controllers_see_the_passing_order() {
+        let config = GameConfig::builder().seed(75).build().unwrap();
+        let mut game = Game::new(config, PassRecorder::default()).unwrap();
+
+        game.round().unwrap();
+        game.round().unwrap();
+
+        let passes = game.controller().passes.borrow();
+        assert_eq!(passes[..4], [0, 1, 2, 3].map(|i| (PlayerId(i), PlayerId((i + 3) % 4), "right")));
+        assert_eq!(passes[4..], [0, 1, 2, 3].map(|i| (PlayerId(i), PlayerId((i + 2) % 4), "across")));
+    }
 }
@@ -1,34 +1,333 @@
 use super::{
     card::{Card, Rank, Suit},
-    player::Player,
+    player::{Player, PlayerId},
 };
 
-use iter_tools::Itertools;
-use rand::prelude::SliceRandom;
-use strum::IntoEnumIterator;
+use rand::{prelude::SliceRandom, rngs::StdRng, SeedableRng};
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum DeckError {
+    #[error("Hearts cannot be played with {0} players.")]
+    UnsupportedPlayerCount(usize),
+    #[error("{cards} cards cannot be dealt evenly between {players} players.")]
+    UnevenDeal { cards: usize, players: usize },
+    #[error("No deal satisfied the constraints after {0} attempts.")]
+    UnsatisfiableDeal(usize),
+}
+
+pub const MAX_BIASED_DEAL_ATTEMPTS: usize = 10_000;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DealConstraint {
+    Holds { player: PlayerId, card: Card },
+    HoldsAtLeast { player: PlayerId, suit: Suit, count: usize },
+}
+
+impl DealConstraint {
+    fn is_satisfied(&self, hands: &[Vec<Card>]) -> bool {
+        match self {
+            Self::Holds { player, card } => hands.get(player.0).is_some_and(|hand| hand.contains(card)),
+            Self::HoldsAtLeast { player, suit, count } => hands
+                .get(player.0)
+                .is_some_and(|hand| hand.iter().filter(|card| card.suit == *suit).count() >= *count),
+        }
+    }
+}
 
 pub struct Deck {
     cards: Vec<Card>,
+    rng: StdRng,
+    stacked: bool,
+    kitty: Vec<Card>,
 }
 
 impl Deck {
     pub fn new() -> Self {
         Self {
-            cards: Rank::iter()
-                .cartesian_product(Suit::iter())
-                .map(|(rank, suit)| Card::new(rank, suit))
-                .collect(),
+            cards: Card::all(),
+            rng: StdRng::from_rng(rand::thread_rng()).expect("Thread RNG should be able to seed the deck"),
+            stacked: false,
+            kitty: vec![],
         }
     }
 
-    fn shuffle(&mut self) {
-        self.cards.shuffle(&mut rand::thread_rng());
+    pub fn with_seed(seed: u64) -> Self {
+        Self { cards: Card::all(), rng: StdRng::seed_from_u64(seed), stacked: false, kitty: vec![] }
+    }
+
+    pub fn stacked(cards: Vec<Card>) -> Self {
+        Self { cards, stacked: true, ..Self::with_seed(0) }
+    }
+
+    pub fn for_players(player_count: usize) -> Result<Self, DeckError> {
+        Self::new().trimmed(player_count)
     }
 
-    pub fn deal(&mut self, players: &[Player]) {
-        self.shuffle();
-        for (i, player) in players.iter().enumerate() {
-            player.take(self.cards[(i * 13)..((i + 1) * 13)].to_vec());
+    pub fn for_players_with_seed(player_count: usize, seed: u64) -> Result<Self, DeckError> {
+        Self::with_seed(seed).trimmed(player_count)
+    }
+
+    fn trimmed(mut self, player_count: usize) -> Result<Self, DeckError> {
+        // Five players drop the 3♦ instead of the 2♣ so the Two of Clubs can still open every round.
+        let removed_cards = match player_count {
+            3 => vec![Card::new(Rank::Two, Suit::Diamonds)],
+            4 | 6 => vec![],
+            5 => vec![Card::new(Rank::Two, Suit::Diamonds), Card::new(Rank::Three, Suit::Diamonds)],
+            _ => return Err(DeckError::UnsupportedPlayerCount(player_count)),
+        };
+
+        self.cards.retain(|card| !removed_cards.contains(card));
+        Ok(self)
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cards.is_empty()
+    }
+
+    fn fresh_cards(&mut self) -> Vec<Card> {
+        let mut cards = self.cards.clone();
+        if !self.stacked {
+            cards.shuffle(&mut self.rng);
         }
+        cards
+    }
+
+    pub fn kitty(&self) -> &[Card] {
+        &self.kitty
+    }
+
+    pub fn take_kitty(&mut self) -> Vec<Card> {
+        std::mem::take(&mut self.kitty)
+    }
+
+    pub fn restore_kitty(&mut self, kitty: Vec<Card>) {
+        self.kitty = kitty;
+    }
+
+    pub fn deal_all(&mut self, players: usize) -> Result<Vec<Vec<Card>>, DeckError> {
+        self.check_deal(players)?;
+        Ok(self.deal_hands(players))
+    }
+
+    fn deal_hands(&mut self, players: usize) -> Vec<Vec<Card>> {
+        let mut cards = self.fresh_cards();
+        self.kitty = cards.split_off(cards.len() - cards.len() % players);
+        if let Some(position) = self.kitty.iter().position(Card::is_two_of_clubs) {
+            std::mem::swap(&mut self.kitty[position], &mut cards[0]);
+        }
+
+        let hand_size = cards.len() / players;
+        cards.chunks(hand_size).map(<[Card]>::to_vec).collect()
+    }
+
+    pub fn deal(&mut self, players: &[Player]) -> Result<(), DeckError> {
+        let hands = self.deal_all(players.len())?;
+        Self::give_hands(players, hands);
+        Ok(())
+    }
+
+    pub fn deal_biased(
+        &mut self, players: &[Player], constraints: &[DealConstraint],
+    ) -> Result<(), DeckError> {
+        self.check_deal(players.len())?;
+        for _ in 0..MAX_BIASED_DEAL_ATTEMPTS {
+            let hands = self.deal_hands(players.len());
+            if constraints.iter().all(|constraint| constraint.is_satisfied(&hands)) {
+                Self::give_hands(players, hands);
+                return Ok(());
+            }
+        }
+
+        Err(DeckError::UnsatisfiableDeal(MAX_BIASED_DEAL_ATTEMPTS))
+    }
+
+    fn check_deal(&self, player_count: usize) -> Result<(), DeckError> {
+        if !(3..=6).contains(&player_count) {
+            return Err(DeckError::UnsupportedPlayerCount(player_count));
+        }
+        if player_count != 6 && self.cards.len() % player_count != 0 {
+            return Err(DeckError::UnevenDeal { cards: self.cards.len(), players: player_count });
+        }
+
+        Ok(())
+    }
+
+    fn give_hands(players: &[Player], hands: Vec<Vec<Card>>) {
+        for (player, hand) in players.iter().zip(hands) {
+            player.clear_hand();
+            player.take(hand);
+        }
+    }
+}
+
+impl Default for Deck {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod fixtures {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+
+    /// The suit each seat holds in a [`suited_deck`], in seat order.
+    pub(crate) const SUITS: [Suit; 4] = [Suit::Clubs, Suit::Diamonds, Suit::Spades, Suit::Hearts];
+
+    /// A four-player deck where each seat is dealt one whole suit.
+    pub(crate) fn suited_deck() -> Deck {
+        Deck::stacked(SUITS.iter().flat_map(|suit| Rank::iter().map(|rank| Card::new(rank, *suit))).collect())
+    }
+
+    /// The thirteen tricks of a [`suited_deck`] round, each played in seat order from the two upward.
+    pub(crate) fn suited_tricks() -> Vec<[Card; 4]> {
+        Rank::iter().map(|rank| SUITS.map(|suit| Card::new(rank, suit))).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn players(count: usize) -> Vec<Player> {
+        (0..count).map(|i| Player::new(PlayerId(i), format!("Player {i}"))).collect()
+    }
+
+    #[test]
+    fn deals_even_hands_with_two_of_clubs() {
+        for (count, hand_size) in [(3, 17), (4, 13), (5, 10)] {
+            let players = players(count);
+            let mut deck = Deck::for_players_with_seed(count, 1).unwrap();
+            deck.deal(&players).unwrap();
+
+            assert!(players.iter().all(|player| player.hand().len() == hand_size));
+            assert_eq!(players.iter().filter(|player| player.has_two_of_clubs()).count(), 1);
+            assert!(deck.kitty().is_empty());
+        }
+    }
+
+    #[test]
+    fn rejects_unsupported_player_counts() {
+        assert!(matches!(Deck::for_players(2), Err(DeckError::UnsupportedPlayerCount(2))));
+        assert!(matches!(Deck::for_players(7), Err(DeckError::UnsupportedPlayerCount(7))));
+    }
+
+    #[test]
+    fn same_seed_deals_same_hands() {
+        let (first, second) = (players(4), players(4));
+        let mut first_deck = Deck::with_seed(42);
+        let mut second_deck = Deck::with_seed(42);
+
+        for _ in 0..3 {
+            first_deck.deal(&first).unwrap();
+            second_deck.deal(&second).unwrap();
+            for (a, b) in first.iter().zip(&second) {
+                assert_eq!(*a.hand(), *b.hand());
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_uneven_deal() {
+        let mut deck = Deck::with_seed(0);
+
+        assert!(matches!(deck.deal(&players(3)), Err(DeckError::UnevenDeal { cards: 52, players: 3 })));
+    }
+
+    #[test]
+    fn stacked_deck_deals_in_order() {
+        let players = players(4);
+        let cards = Card::all();
+        let mut deck = Deck::stacked(cards.clone());
+
+        for _ in 0..2 {
+            deck.deal(&players).unwrap();
+            for (player, hand) in players.iter().zip(cards.chunks(13)) {
+                assert_eq!(*player.hand(), hand);
+            }
+        }
+    }
+
+    #[test]
+    fn dealing_twice_gives_full_hands() {
+        let players = players(4);
+        let mut deck = Deck::with_seed(47);
+
+        for _ in 0..2 {
+            deck.deal(&players).unwrap();
+            assert!(players.iter().all(|player| player.hand().len() == 13));
+            assert_eq!(deck.len(), 52);
+            assert!(!deck.is_empty());
+        }
+    }
+
+    #[test]
+    fn six_players_leave_a_kitty() {
+        let players = players(6);
+        let mut deck = Deck::for_players_with_seed(6, 59).unwrap();
+
+        deck.deal(&players).unwrap();
+
+        assert!(players.iter().all(|player| player.hand().len() == 8));
+        assert_eq!(deck.kitty().len(), 4);
+        assert_eq!(players.iter().filter(|player| player.has_two_of_clubs()).count(), 1);
+        assert!(!deck.kitty().iter().any(Card::is_two_of_clubs));
+    }
+
+    #[test]
+    fn deal_all_covers_every_card_once() {
+        let mut deck = Deck::with_seed(62);
+
+        let hands = deck.deal_all(4).unwrap();
+        let mut dealt = hands.concat();
+        dealt.sort_by(Card::cmp_by_suit_then_rank);
+        let mut expected = Card::all();
+        expected.sort_by(Card::cmp_by_suit_then_rank);
+
+        assert_eq!(hands.len(), 4);
+        assert!(hands.iter().all(|hand| hand.len() == 13));
+        assert_eq!(dealt, expected);
+        assert!(matches!(deck.deal_all(0), Err(DeckError::UnsupportedPlayerCount(0))));
+        assert!(matches!(deck.deal_all(3), Err(DeckError::UnevenDeal { cards: 52, players: 3 })));
+    }
+
+    #[test]
+    fn biased_deal_gives_player_zero_the_queen() {
+        let players = players(4);
+        let queen = Card::new(Rank::Queen, Suit::Spades);
+        let mut deck = Deck::with_seed(99);
+
+        for _ in 0..5 {
+            deck.deal_biased(&players, &[DealConstraint::Holds { player: PlayerId(0), card: queen }])
+                .unwrap();
+            assert!(players[0].hand().contains(&queen));
+            assert!(players.iter().all(|player| player.hand().len() == 13));
+        }
+    }
+
+    #[test]
+    fn unsatisfiable_biased_deal_is_an_error() {
+        let players = players(4);
+        let queen = Card::new(Rank::Queen, Suit::Spades);
+        let mut deck = Deck::with_seed(99);
+        let constraints = [
+            DealConstraint::Holds { player: PlayerId(0), card: queen },
+            DealConstraint::Holds { player: PlayerId(1), card: queen },
+        ];
+
+        assert!(matches!(
+            deck.deal_biased(&players, &constraints),
+            Err(DeckError::UnsatisfiableDeal(MAX_BIASED_DEAL_ATTEMPTS))
+        ));
     }
 }
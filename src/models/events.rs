@@ -0,0 +1,14 @@
+use super::{card::Card, game::MoonOption};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameEvent {
+    NamesChosen(Vec<String>),
+    CardsPassed { from: String, to: String, cards: Vec<Card> },
+    CardPlaced { player: String, card: Card },
+    MoonOptionChosen { player: String, option: MoonOption },
+    TrickWon { player: String, card: Card, score: i16 },
+    RoundScored { round: usize, scores: Vec<(String, i16)> },
+    MoonShot { player: String, option: MoonOption },
+    GameOver { winners: Vec<String> },
+}
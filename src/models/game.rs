@@ -1,19 +1,23 @@
-use std::{
-    fmt::Display,
-    iter::{zip, Cycle},
-};
+use std::{collections::HashSet, fmt::Display, iter::zip};
 
+use iter_tools::Itertools;
 use strum::IntoEnumIterator;
 use strum_macros::EnumIter;
 use thiserror::Error;
 
-use super::{card::Card, controller::Controller, deck::Deck, player::Player};
-
-pub enum HeartsPlayedState<'a> {
-    NoHeartsPlayed,
-    HeartsPlayedOne(&'a Player),
-    HeartsPlayedMany,
-}
+#[cfg(feature = "async")]
+use super::async_controller::AsyncController;
+use super::{
+    card::{Card, RankOrder},
+    config::{GameConfig, MoonRule, SunRule},
+    controller::{Controller, ControllerError},
+    deck::{Deck, DeckError},
+    events::GameEvent,
+    observer::Observer,
+    player::{are_valid_names, Player, PlayerId},
+    state::{GameState, PlayerState, RoundState},
+    tracker::PlayedTracker,
+};
 
 #[derive(EnumIter)]
 pub enum PassingOrder {
@@ -23,189 +27,2402 @@ pub enum PassingOrder {
     Hold,
 }
 
+impl PassingOrder {
+    pub fn direction(&self) -> &'static str {
+        match self {
+            Self::Right => "right",
+            Self::Across => "across",
+            Self::Left => "left",
+            Self::Hold => "nowhere",
+        }
+    }
+
+    pub fn describe(&self, players: &[Player]) -> String {
+        match passing_indices(self, players.len()) {
+            Some(passing_indices) => passing_indices
+                .into_iter()
+                .map(|(from, to)| format!("{} → {}", players[from], players[to]))
+                .join(", "),
+            None => PassingOrder::Hold.to_string(),
+        }
+    }
+}
+
 impl Display for PassingOrder {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
             "{}",
             match self {
-                Self::Right => "P1 -> P2 -> P3 -> P4 -> P1",
+                Self::Right => "P1 <- P2 <- P3 <- P4 <- P1",
                 Self::Across => "P1 <-> P3, P2 <-> P4",
-                Self::Left => "P1 <- P2 <- P3 <- P4 <- P1",
+                Self::Left => "P1 -> P2 -> P3 -> P4 -> P1",
                 Self::Hold => "Hold",
             }
         )
     }
 }
 
+#[derive(Clone, Copy, Debug, EnumIter, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoonOption {
+    AddToOthers,
+    SubtractFromSelf,
+}
+
+impl Display for MoonOption {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::AddToOthers => "Add the round's points to everyone else",
+                Self::SubtractFromSelf => "Subtract the round's points from yourself",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Error)]
 pub enum GameError {
     #[error("Could not start game.")]
     StartError,
+    #[error("Could not get player names.")]
+    NamesError(#[source] ControllerError),
+    #[error("Could not deal cards.")]
+    DealError(#[from] DeckError),
     #[error("Could not pass cards.")]
-    PassError,
+    PassError(#[source] ControllerError),
+    #[error("{player} cannot pass the Queen of Spades.")]
+    IllegalPass { player: String },
+    #[error("{player} selected the same card more than once.")]
+    DuplicatePass { player: String },
+    #[error("{player} selected a card they do not hold.")]
+    MissingPassCard { player: String },
+    #[error("{player} must pass {expected} cards, not {found}.")]
+    PassCountError { player: String, expected: usize, found: usize },
     #[error("Could not complete turn.")]
-    TurnError,
+    TurnError(#[source] ControllerError),
+    #[error("{player} cannot play the {card}.")]
+    IllegalMove { player: String, card: Card },
+    #[error("Could not score the moon.")]
+    MoonError(#[source] ControllerError),
+    #[error("The round does not play out every hand.")]
+    IncompleteRound,
+}
+
+impl GameError {
+    pub fn is_cancelled(&self) -> bool {
+        matches!(
+            self,
+            Self::NamesError(ControllerError::Cancelled)
+                | Self::PassError(ControllerError::Cancelled)
+                | Self::TurnError(ControllerError::Cancelled)
+                | Self::MoonError(ControllerError::Cancelled)
+        )
+    }
 }
 
 type GameResult<T> = Result<T, GameError>;
 
-pub struct Game<C: Controller> {
-    pub players: Vec<Player>,
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameOutcome {
+    pub names: Vec<String>,
+    pub scores: Vec<i16>,
+    pub winners: Vec<PlayerId>,
+    pub rounds: usize,
+    pub round_scores: Vec<Vec<i16>>,
+}
+
+#[cfg(feature = "json")]
+impl GameOutcome {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).expect("Game outcome should serialize to JSON")
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MoonStatus {
+    NoPointsTaken,
+    OnePlayer(PlayerId),
+    ManyPlayers,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RoundResult {
+    pub trick_points: Vec<i16>,
+    pub applied_scores: Vec<i16>,
+    pub trick_winners: Vec<PlayerId>,
+    pub tricks_won: Vec<u8>,
+    pub moon_shooter: Option<PlayerId>,
+    pub sun_shooter: Option<PlayerId>,
+    pub max_score: i16,
+}
+
+pub fn legal_moves(
+    hand: &[Card], table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+    queen_allowed_first_trick: bool,
+) -> Vec<Card> {
+    let mut moves = hand.to_vec();
+
+    if is_first_trick && table.is_empty() {
+        moves = narrow_moves(moves, Card::is_two_of_clubs);
+    }
+    if let Some((_, first_card)) = table.first() {
+        moves = narrow_moves(moves, |card| card.suit == first_card.suit);
+    }
+    if is_first_trick {
+        moves = narrow_moves(moves, |card| {
+            !card.is_point_card() || (queen_allowed_first_trick && card.is_queen_of_spades())
+        });
+    }
+    if table.is_empty() && !hearts_broken {
+        moves = narrow_moves(moves, |card| !card.is_hearts());
+    }
+
+    moves
+}
+
+pub fn suggest_card(
+    hand: &[Card], table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+    queen_allowed_first_trick: bool, rank_order: RankOrder,
+) -> Option<Card> {
+    let moves = legal_moves(hand, table, is_first_trick, hearts_broken, queen_allowed_first_trick);
+    let value = |card: &Card| rank_order.value(card.rank);
+    let Some((_, led_card)) = table.first() else {
+        return moves.into_iter().min_by_key(value);
+    };
+
+    let winning_card = table
+        .iter()
+        .map(|(_, card)| *card)
+        .reduce(
+            |winning, card| {
+                if card.beats_with(&winning, led_card.suit, rank_order) {
+                    card
+                } else {
+                    winning
+                }
+            },
+        )
+        .expect("Table should not be empty");
+    let ducks =
+        moves.iter().copied().filter(|card| !card.beats_with(&winning_card, led_card.suit, rank_order));
+    ducks.max_by_key(value).or_else(|| moves.into_iter().min_by_key(value))
+}
+
+pub fn passing_indices(passing_order: &PassingOrder, player_count: usize) -> Option<Vec<(usize, usize)>> {
+    let offset = match passing_order {
+        PassingOrder::Left => 1,
+        PassingOrder::Right => player_count - 1,
+        PassingOrder::Across if player_count % 2 == 0 => player_count / 2,
+        _ => return None,
+    };
+
+    Some((0..player_count).map(|i| (i, (i + offset) % player_count)).collect())
+}
+
+fn narrow_moves(moves: Vec<Card>, predicate: impl Fn(&Card) -> bool) -> Vec<Card> {
+    let narrowed_moves = moves.iter().copied().filter(|card| predicate(card)).collect::<Vec<_>>();
+    if narrowed_moves.is_empty() {
+        moves
+    } else {
+        narrowed_moves
+    }
+}
 
+pub struct Game<C: Observer> {
+    players: Vec<Player>,
+    config: GameConfig,
     deck: Deck,
-    passing_order: Cycle<PassingOrderIter>,
+    passing_order_index: usize,
+    round_number: usize,
+    round_state: Option<RoundState>,
+    last_trick: Option<(Vec<(PlayerId, Card)>, PlayerId)>,
+    played: PlayedTracker,
     controller: C,
+    observers: Vec<Box<dyn Observer>>,
 }
 
-impl<'a, C> Game<C>
+impl<C> Game<C>
 where
     C: Controller,
 {
-    pub fn new(controller: C) -> GameResult<Self> {
+    pub fn new(config: GameConfig, controller: C) -> GameResult<Self> {
+        let deck = Self::deck(&config, config.player_count)?;
+        let names = controller.get_names(config.player_count).map_err(GameError::NamesError)?;
+        Self::with_names(config, deck, controller, names)
+    }
+
+    pub fn with_controller(controller: C) -> GameResult<Self> {
+        Self::new(GameConfig::default(), controller)
+    }
+
+    pub fn round(&mut self) -> GameResult<RoundResult> {
+        if let Some(round) = self.round_state.take() {
+            return self.resume_round(round);
+        }
+
+        let next_passing_order = self.start_round()?;
+        let passed = self.pass_cards(&next_passing_order);
+        let round = self.begin_tricks(passed)?;
+        self.play_tricks(round)
+    }
+
+    pub fn resume_round(&mut self, round: RoundState) -> GameResult<RoundResult> {
+        self.restore_round(&round);
+        self.play_tricks(round)
+    }
+
+    fn play_tricks(&mut self, mut round: RoundState) -> GameResult<RoundResult> {
+        while self.has_cards_left() {
+            let is_first_turn = round.trick_winners.is_empty();
+            let trick = self.turn(round.starting_id, &mut round.table, is_first_turn, round.hearts_broken);
+            round = self.record_trick(round, trick)?;
+        }
+
+        let moon_option = self.pending_moon_shooter(&round).map(|moon_shooter| {
+            self.shoot_moon(&self.players[moon_shooter.0]).map(|option| (moon_shooter, option))
+        });
+        self.settle_round(round, moon_option)
+    }
+
+    fn turn(
+        &self, starting_id: PlayerId, table: &mut Vec<(PlayerId, Card)>, is_first_turn: bool,
+        hearts_broken: bool,
+    ) -> GameResult<(PlayerId, Card, i16, u16, bool)> {
+        for id in self.pending_turns(starting_id, table) {
+            let card_choice = match self.start_turn(id, table, is_first_turn, hearts_broken) {
+                Some(card) => card,
+                None => self
+                    .controller
+                    .get_card_to_place(
+                        &self.players[id.0],
+                        table,
+                        is_first_turn,
+                        hearts_broken,
+                        self.config.queen_allowed_first_trick,
+                    )
+                    .map_err(GameError::TurnError)?,
+            };
+            self.place_card(id, card_choice, table, is_first_turn, hearts_broken)?;
+        }
+
+        Ok(self.resolve_trick(table))
+    }
+
+    fn shoot_moon(&self, moon_shooter: &Player) -> GameResult<MoonOption> {
+        match self.moon_rule_option(moon_shooter) {
+            Some(moon_option) => Ok(moon_option),
+            None => self.controller.choose_moon_option(moon_shooter).map_err(GameError::MoonError),
+        }
+    }
+
+    pub fn pass_cards(&mut self, passing_order: &PassingOrder) -> GameResult<()> {
+        let Some(passing_indices) = passing_indices(passing_order, self.players.len()) else {
+            return Ok(());
+        };
+
+        let mut selections = vec![];
+        for (a, b) in passing_indices {
+            let suggested = self.preview_pass(a);
+            let card_choices = self
+                .controller
+                .get_cards_to_pass(
+                    &self.players[a],
+                    &self.players[b],
+                    passing_order,
+                    self.config.pass_count,
+                    self.config.allow_passing_queen,
+                    &suggested,
+                )
+                .map_err(GameError::PassError)?;
+            self.check_pass(a, &card_choices)?;
+            selections.push((a, b, card_choices));
+        }
+
+        self.exchange_passes(selections);
+        Ok(())
+    }
+
+    pub fn play(&mut self) -> GameResult<GameOutcome> {
+        while !self.is_over() {
+            self.round()?;
+        }
+
+        Ok(self.outcome())
+    }
+}
+
+#[cfg(feature = "async")]
+impl<C> Game<C>
+where
+    C: AsyncController,
+{
+    pub async fn new_async(config: GameConfig, controller: C) -> GameResult<Self> {
+        let deck = Self::deck(&config, config.player_count)?;
+        let names = controller.get_names(config.player_count).await.map_err(GameError::NamesError)?;
+        Self::with_names(config, deck, controller, names)
+    }
+
+    pub async fn round_async(&mut self) -> GameResult<RoundResult> {
+        if let Some(round) = self.round_state.take() {
+            return self.resume_round_async(round).await;
+        }
+
+        let next_passing_order = self.start_round()?;
+        let passed = self.pass_cards_async(&next_passing_order).await;
+        let round = self.begin_tricks(passed)?;
+        self.play_tricks_async(round).await
+    }
+
+    pub async fn resume_round_async(&mut self, round: RoundState) -> GameResult<RoundResult> {
+        self.restore_round(&round);
+        self.play_tricks_async(round).await
+    }
+
+    async fn play_tricks_async(&mut self, mut round: RoundState) -> GameResult<RoundResult> {
+        while self.has_cards_left() {
+            let is_first_turn = round.trick_winners.is_empty();
+            let trick = self
+                .turn_async(round.starting_id, &mut round.table, is_first_turn, round.hearts_broken)
+                .await;
+            round = self.record_trick(round, trick)?;
+        }
+
+        let moon_option = match self.pending_moon_shooter(&round) {
+            Some(moon_shooter) => Some(
+                self.shoot_moon_async(&self.players[moon_shooter.0])
+                    .await
+                    .map(|option| (moon_shooter, option)),
+            ),
+            None => None,
+        };
+        self.settle_round(round, moon_option)
+    }
+
+    async fn turn_async(
+        &self, starting_id: PlayerId, table: &mut Vec<(PlayerId, Card)>, is_first_turn: bool,
+        hearts_broken: bool,
+    ) -> GameResult<(PlayerId, Card, i16, u16, bool)> {
+        for id in self.pending_turns(starting_id, table) {
+            let card_choice = match self.start_turn(id, table, is_first_turn, hearts_broken) {
+                Some(card) => card,
+                None => self
+                    .controller
+                    .get_card_to_place(
+                        &self.players[id.0],
+                        table,
+                        is_first_turn,
+                        hearts_broken,
+                        self.config.queen_allowed_first_trick,
+                    )
+                    .await
+                    .map_err(GameError::TurnError)?,
+            };
+            self.place_card(id, card_choice, table, is_first_turn, hearts_broken)?;
+        }
+
+        Ok(self.resolve_trick(table))
+    }
+
+    async fn shoot_moon_async(&self, moon_shooter: &Player) -> GameResult<MoonOption> {
+        match self.moon_rule_option(moon_shooter) {
+            Some(moon_option) => Ok(moon_option),
+            None => self.controller.choose_moon_option(moon_shooter).await.map_err(GameError::MoonError),
+        }
+    }
+
+    pub async fn pass_cards_async(&mut self, passing_order: &PassingOrder) -> GameResult<()> {
+        let Some(passing_indices) = passing_indices(passing_order, self.players.len()) else {
+            return Ok(());
+        };
+
+        let mut selections = vec![];
+        for (a, b) in passing_indices {
+            let suggested = self.preview_pass(a);
+            let card_choices = self
+                .controller
+                .get_cards_to_pass(
+                    &self.players[a],
+                    &self.players[b],
+                    passing_order,
+                    self.config.pass_count,
+                    self.config.allow_passing_queen,
+                    &suggested,
+                )
+                .await
+                .map_err(GameError::PassError)?;
+            self.check_pass(a, &card_choices)?;
+            selections.push((a, b, card_choices));
+        }
+
+        self.exchange_passes(selections);
+        Ok(())
+    }
+
+    pub async fn play_async(&mut self) -> GameResult<GameOutcome> {
+        while !self.is_over() {
+            self.round_async().await?;
+        }
+
+        Ok(self.outcome())
+    }
+}
+
+impl<C> Game<C>
+where
+    C: Observer,
+{
+    fn with_names(config: GameConfig, deck: Deck, controller: C, names: Vec<String>) -> GameResult<Self> {
+        if !are_valid_names(&names) {
+            return Err(GameError::StartError);
+        }
+
+        let players: Vec<Player> = names
+            .into_iter()
+            .enumerate()
+            .map(|(i, name)| Player::new(PlayerId(i), name.trim().to_owned()))
+            .collect();
+        if players.len() != config.player_count {
+            return Err(GameError::StartError);
+        }
+
         Ok(Self {
-            players: controller
-                .get_names()
-                .map_err(|_| GameError::StartError)?
-                .into_iter()
-                .map(|name| Player::new(name))
+            config,
+            played: PlayedTracker::new(deck.cards().to_vec()),
+            deck,
+            players,
+            passing_order_index: 0,
+            round_number: 0,
+            round_state: None,
+            last_trick: None,
+            controller,
+            observers: vec![],
+        })
+    }
+
+    pub fn save(&self) -> GameState {
+        GameState {
+            players: self
+                .players
+                .iter()
+                .map(|player| PlayerState {
+                    name: player.name.clone(),
+                    hand: player.hand().clone(),
+                    score: player.score(),
+                    round_scores: player.round_scores().clone(),
+                })
                 .collect(),
-            deck: Deck::new(),
-            passing_order: PassingOrder::iter().cycle(),
+            passing_order_index: self.passing_order_index,
+            round_number: self.round_number,
+            round: self.round_state.clone(),
+            config: self.config,
+        }
+    }
+
+    pub fn load(state: GameState, controller: C) -> GameResult<Self> {
+        let players: Vec<Player> = state
+            .players
+            .into_iter()
+            .enumerate()
+            .map(|(i, player_state)| {
+                Player::restore(
+                    PlayerId(i),
+                    player_state.name,
+                    player_state.hand,
+                    player_state.score,
+                    player_state.round_scores,
+                )
+            })
+            .collect();
+
+        let deck = Self::deck(&state.config, players.len())?;
+        Ok(Self {
+            config: state.config,
+            played: PlayedTracker::new(deck.cards().to_vec()),
+            deck,
+            players,
+            passing_order_index: state.passing_order_index,
+            round_number: state.round_number,
+            round_state: state.round,
+            last_trick: None,
             controller,
+            observers: vec![],
         })
     }
 
-    pub fn round(&mut self) -> GameResult<u8> {
-        self.deck.deal(&self.players);
+    fn deck(config: &GameConfig, player_count: usize) -> GameResult<Deck> {
+        let deck = match config.seed {
+            Some(seed) => Deck::for_players_with_seed(player_count, seed),
+            None => Deck::for_players(player_count),
+        };
 
-        let next_passing_order = self.passing_order.next().expect("Passing order should exist");
-        self.controller.display_passing_order(&next_passing_order);
-        self.pass_cards(&next_passing_order)?;
+        deck.map_err(GameError::DealError)
+    }
 
-        self.controller.display_round_start();
+    pub fn with_deck(self, deck: Deck) -> Self {
+        Self { played: PlayedTracker::new(deck.cards().to_vec()), deck, ..self }
+    }
 
-        let mut hearts_played_state = HeartsPlayedState::NoHeartsPlayed;
-        let mut starting_index = self
-            .players
-            .iter()
-            .enumerate()
-            .find_map(|(i, player)| if player.has_two_of_clubs() { Some(i) } else { None })
-            .expect("At least one player should start with the Two of Clubs");
+    pub fn played(&self) -> &PlayedTracker {
+        &self.played
+    }
 
-        let mut scores = [0; 4];
-        for turn in 1..=13 {
-            let (winner_index, winning_card, score, hearts_played) =
-                self.turn(starting_index, turn == 1, &hearts_played_state)?;
+    pub fn controller(&self) -> &C {
+        &self.controller
+    }
 
-            if hearts_played {
-                hearts_played_state = match hearts_played_state {
-                    HeartsPlayedState::NoHeartsPlayed => {
-                        HeartsPlayedState::HeartsPlayedOne(&self.players[winner_index])
-                    }
-                    HeartsPlayedState::HeartsPlayedOne(player) if player == &self.players[winner_index] => {
-                        HeartsPlayedState::HeartsPlayedOne(player)
-                    }
-                    _ => HeartsPlayedState::HeartsPlayedMany,
-                };
-            }
+    pub fn add_observer(&mut self, observer: Box<dyn Observer>) {
+        self.observers.push(observer);
+    }
+
+    fn notify(&self, notification: impl Fn(&dyn Observer)) {
+        notification(&self.controller);
+        for observer in &self.observers {
+            notification(observer.as_ref());
+        }
+    }
+
+    fn emit(&self, event: GameEvent) {
+        self.notify(|observer| observer.on_event(&event));
+    }
+
+    pub fn suggest_card(
+        &self, player_id: PlayerId, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+    ) -> Option<Card> {
+        self.players[player_id.0].suggested_card(
+            table,
+            is_first_trick,
+            hearts_broken,
+            self.config.queen_allowed_first_trick,
+            self.config.rank_order,
+        )
+    }
 
-            self.controller.display_winner(&self.players[winner_index], winning_card, score);
+    pub fn last_trick(&self) -> Option<(&[(PlayerId, Card)], PlayerId)> {
+        self.last_trick.as_ref().map(|(trick, winner_id)| (trick.as_slice(), *winner_id))
+    }
 
-            scores[winner_index] += score;
-            starting_index = winner_index;
+    pub fn moon_status(&self) -> MoonStatus {
+        let point_takers =
+            self.players.iter().filter(|player| player.points_taken() > 0).map(Player::id).collect_vec();
+        match point_takers[..] {
+            [] => MoonStatus::NoPointsTaken,
+            [player_id] => MoonStatus::OnePlayer(player_id),
+            _ => MoonStatus::ManyPlayers,
         }
+    }
 
-        for (player, score) in zip(&self.players, scores) {
-            if let HeartsPlayedState::HeartsPlayedOne(hearts_player) = hearts_played_state {
-                if player != hearts_player {
-                    player.add_score(26);
-                }
-            } else {
-                player.add_score(score);
-            }
+    pub fn round_number(&self) -> usize {
+        self.round_number
+    }
+
+    pub fn players(&self) -> &[Player] {
+        &self.players
+    }
+
+    pub fn player(&self, id: PlayerId) -> &Player {
+        &self.players[id.0]
+    }
+
+    pub fn target_score(&self) -> i16 {
+        self.config.target_score
+    }
+
+    fn start_round(&mut self) -> GameResult<PassingOrder> {
+        if self.round_number == 0 {
+            self.notify(|observer| observer.display_game_start(&self.config, &self.players));
         }
+        self.deck.deal(&self.players)?;
+        self.last_trick = None;
 
-        self.controller.display_scores(&self.players);
+        let next_passing_order = if self.config.no_passing {
+            PassingOrder::Hold
+        } else {
+            let passing_order = PassingOrder::iter()
+                .nth(self.passing_order_index % PassingOrder::iter().len())
+                .expect("Passing order should exist");
+            self.passing_order_index += 1;
+            passing_order
+        };
+        self.round_number += 1;
+        self.notify(|observer| observer.display_passing_order(&next_passing_order, &self.players));
 
-        Ok(self.max_score())
+        Ok(next_passing_order)
     }
 
-    fn turn(
-        &'a self, starting_index: usize, is_first_turn: bool, hearts_played_state: &HeartsPlayedState<'a>,
-    ) -> GameResult<(usize, Card, u8, bool)> {
-        let mut table = vec![];
+    fn begin_tricks(&mut self, passed: GameResult<()>) -> GameResult<RoundState> {
+        if let Err(error) = passed {
+            self.undo_start_round();
+            return Err(error);
+        }
 
-        let mut is_first_move = is_first_turn;
-        for i in Self::round_order(starting_index) {
-            let card_choice = self
-                .controller
-                .get_card_to_place(&self.players[i], &table, is_first_move, hearts_played_state)
-                .map_err(|_| GameError::TurnError)?;
+        let starting_id = self.start_tricks();
+        Ok(RoundState::new(starting_id, self.players.len()))
+    }
+
+    fn undo_start_round(&mut self) {
+        self.round_number -= 1;
+        if !self.config.no_passing {
+            self.passing_order_index -= 1;
+        }
+    }
+
+    fn start_tricks(&self) -> PlayerId {
+        self.played.reset();
+        self.notify(|observer| observer.display_round_start(self.round_number));
+        for player in &self.players {
+            player.reset_tricks();
+        }
+
+        self.players
+            .iter()
+            .find(|player| player.has_two_of_clubs())
+            .map(Player::id)
+            .expect("At least one player should start with the Two of Clubs")
+    }
+
+    fn has_cards_left(&self) -> bool {
+        self.players.iter().any(|player| !player.hand().is_empty())
+    }
+
+    fn pending_turns(&self, starting_id: PlayerId, table: &[(PlayerId, Card)]) -> Vec<PlayerId> {
+        self.round_order(starting_id).into_iter().skip(table.len()).collect()
+    }
+
+    fn start_turn(
+        &self, player_id: PlayerId, table: &[(PlayerId, Card)], is_first_turn: bool, hearts_broken: bool,
+    ) -> Option<Card> {
+        let forced_move = self.forced_move(player_id, table, is_first_turn, hearts_broken);
+        if forced_move.is_none() {
+            self.display_turn(player_id, table);
+        }
+        forced_move
+    }
+
+    fn display_turn(&self, player_id: PlayerId, table: &[(PlayerId, Card)]) {
+        self.notify(|observer| observer.display_table(&self.players, table));
+        self.notify(|observer| observer.display_hand(&self.players[player_id.0]));
+    }
 
-            let placed_card = self.players[i].place(&card_choice).ok_or(GameError::TurnError)?;
-            table.push((i, placed_card));
+    fn forced_move(
+        &self, player_id: PlayerId, table: &[(PlayerId, Card)], is_first_turn: bool, hearts_broken: bool,
+    ) -> Option<Card> {
+        if !self.config.auto_play_forced {
+            return None;
+        }
+
+        let player = &self.players[player_id.0];
+        let card = player
+            .legal_moves(table, is_first_turn, hearts_broken, self.config.queen_allowed_first_trick)
+            .into_iter()
+            .exactly_one()
+            .ok()?;
+        self.notify(|observer| observer.display_forced_move(player, card));
+        Some(card)
+    }
 
-            is_first_move = false;
+    fn place_card(
+        &self, player_id: PlayerId, card_choice: Card, table: &mut Vec<(PlayerId, Card)>,
+        is_first_turn: bool, hearts_broken: bool,
+    ) -> GameResult<()> {
+        if is_first_turn && table.is_empty() && !card_choice.is_two_of_clubs() {
+            return Err(self.illegal_move(player_id, card_choice));
+        }
+        if !legal_moves(
+            &self.players[player_id.0].hand(),
+            table,
+            is_first_turn,
+            hearts_broken,
+            self.config.queen_allowed_first_trick,
+        )
+        .contains(&card_choice)
+        {
+            return Err(self.illegal_move(player_id, card_choice));
         }
 
-        let (winner_index, winning_card) = table
+        let placed_card = self.players[player_id.0]
+            .place(&card_choice)
+            .ok_or_else(|| self.illegal_move(player_id, card_choice))?;
+        self.played.record(placed_card);
+        self.emit(GameEvent::CardPlaced {
+            player: self.players[player_id.0].name.clone(),
+            card: placed_card,
+        });
+        table.push((player_id, placed_card));
+        Ok(())
+    }
+
+    fn illegal_move(&self, player_id: PlayerId, card: Card) -> GameError {
+        GameError::IllegalMove { player: self.players[player_id.0].name.clone(), card }
+    }
+
+    fn resolve_trick(&self, table: &[(PlayerId, Card)]) -> (PlayerId, Card, i16, u16, bool) {
+        let led_suit = table[0].1.suit;
+        let (winner_id, winning_card) = table
             .iter()
-            .filter(|(_, card)| card.suit == table[0].1.suit)
             .copied()
-            .max_by_key(|(_, card)| card.to_owned())
+            .reduce(|winner, challenger| {
+                if challenger.1.beats_with(&winner.1, led_suit, self.config.rank_order) {
+                    challenger
+                } else {
+                    winner
+                }
+            })
             .expect("Table should be filled");
 
-        let score = table.iter().map(|(_, card)| card.score()).sum();
-        let hearts_played = table.iter().any(|(_, card)| card.is_hearts());
+        let score = table.iter().map(|(_, card)| self.card_score(card)).sum();
+        let points = table.iter().map(|(_, card)| u16::from(card.score_with(&self.config.scoring))).sum();
+        let breaks_hearts = table.iter().any(|(_, card)| self.breaks_hearts(card));
 
-        Ok((winner_index, winning_card, score, hearts_played))
+        (winner_id, winning_card, score, points, breaks_hearts)
     }
 
-    pub fn pass_cards(&mut self, passing_order: &PassingOrder) -> GameResult<()> {
-        let Some(passing_indices) = Self::passing_indices(&passing_order) else {
-            return Ok(());
-        };
+    fn win_trick(
+        &self, scores: &mut [i16], winner_id: PlayerId, winning_card: Card, score: i16, points: u16,
+    ) {
+        scores[winner_id.0] += score;
+        self.players[winner_id.0].win_trick(points);
 
-        let mut cards_to_pass = vec![];
-        for (a, b) in passing_indices {
-            let card_choices = self
-                .controller
-                .get_cards_to_pass(&self.players[a], &self.players[b])
-                .map_err(|_| GameError::PassError)?;
-            cards_to_pass.push((b, self.players[a].pass(&card_choices)));
+        self.notify(|observer| observer.display_winner(&self.players[winner_id.0], winning_card, score));
+        self.emit(GameEvent::TrickWon {
+            player: self.players[winner_id.0].name.clone(),
+            card: winning_card,
+            score,
+        });
+    }
+
+    fn complete_trick(&mut self, round: &mut RoundState, trick: (PlayerId, Card, i16, u16, bool)) {
+        let (winner_id, winning_card, score, points, breaks_hearts) = trick;
+        let is_first_turn = round.trick_winners.is_empty();
+
+        let table = std::mem::take(&mut round.table);
+        self.notify(|observer| {
+            observer.display_last_trick(&self.players, &table, &self.players[winner_id.0])
+        });
+        self.last_trick = Some((table, winner_id));
+        round.hearts_broken |= breaks_hearts;
+        self.win_trick(&mut round.scores, winner_id, winning_card, score, points);
+        if is_first_turn {
+            self.award_kitty(&mut round.scores, winner_id);
         }
+        round.trick_winners.push(winner_id);
+        round.starting_id = winner_id;
+    }
 
-        for (i, to_pass) in cards_to_pass {
-            self.players[i].take(to_pass);
+    fn record_trick(
+        &mut self, mut round: RoundState, trick: GameResult<(PlayerId, Card, i16, u16, bool)>,
+    ) -> GameResult<RoundState> {
+        match trick {
+            Ok(trick) => {
+                self.complete_trick(&mut round, trick);
+                Ok(round)
+            }
+            Err(error) => {
+                self.interrupt_round(round);
+                Err(error)
+            }
         }
+    }
 
-        Ok(())
+    fn pending_moon_shooter(&self, round: &RoundState) -> Option<PlayerId> {
+        self.moon_shooter(&round.scores).filter(|_| self.sun_shooter(&round.trick_winners).is_none())
     }
 
-    fn passing_indices(passing_order: &PassingOrder) -> Option<[(usize, usize); 4]> {
-        match passing_order {
-            PassingOrder::Right => Some([(0, 1), (1, 2), (2, 3), (3, 0)]),
-            PassingOrder::Across => Some([(0, 2), (1, 3), (2, 0), (3, 1)]),
-            PassingOrder::Left => Some([(0, 3), (1, 0), (2, 1), (3, 2)]),
-            _ => None,
+    fn settle_round(
+        &mut self, round: RoundState, moon_option: Option<GameResult<(PlayerId, MoonOption)>>,
+    ) -> GameResult<RoundResult> {
+        match moon_option.transpose() {
+            Ok(moon_option) => Ok(self.finish_round(round.scores, round.trick_winners, moon_option)),
+            Err(error) => {
+                self.interrupt_round(round);
+                Err(error)
+            }
         }
     }
 
-    fn max_score(&self) -> u8 {
-        self.players
+    fn interrupt_round(&mut self, mut round: RoundState) {
+        round.points_taken = self.players.iter().map(|player| player.points_taken()).collect();
+        round.played = self.played.played();
+        round.kitty = self.deck.kitty().to_vec();
+        self.round_state = Some(round);
+    }
+
+    fn restore_round(&mut self, round: &RoundState) {
+        self.played.reset();
+        for card in &round.played {
+            self.played.record(*card);
+        }
+        for (player, points_taken) in zip(&self.players, &round.points_taken) {
+            let tricks_won = round.trick_winners.iter().filter(|winner| **winner == player.id()).count();
+            player.restore_tricks(tricks_won as u8, *points_taken);
+        }
+        self.deck.restore_kitty(round.kitty.clone());
+        self.notify(|observer| observer.display_round_start(self.round_number));
+    }
+
+    fn award_kitty(&mut self, scores: &mut [i16], winner_id: PlayerId) {
+        let kitty = self.deck.take_kitty();
+        scores[winner_id.0] += kitty.iter().map(|card| self.card_score(card)).sum::<i16>();
+        self.players[winner_id.0]
+            .take_points(kitty.iter().map(|card| u16::from(card.score_with(&self.config.scoring))).sum());
+    }
+
+    fn moon_shooter(&self, scores: &[i16]) -> Option<PlayerId> {
+        let points_taken = self.players.iter().map(Player::points_taken).collect_vec();
+        self.find_moon_shooter(scores, &points_taken)
+    }
+
+    fn find_moon_shooter(&self, scores: &[i16], points_taken: &[u16]) -> Option<PlayerId> {
+        let moon_shooters = points_taken
             .iter()
-            .map(|player| player.score())
-            .max_by_key(|score| score.to_owned())
-            .expect("At least one player should exist")
-    }
-
-    fn round_order(starting_index: usize) -> [usize; 4] {
-        [
-            (0 + starting_index) % 4,
-            (1 + starting_index) % 4,
-            (2 + starting_index) % 4,
-            (3 + starting_index) % 4,
-        ]
+            .positions(|points| *points == self.config.scoring.total_points())
+            .map(PlayerId)
+            .collect_vec();
+        match moon_shooters[..] {
+            [moon_shooter]
+                if !self.config.jack_of_diamonds || scores[moon_shooter.0] == self.moon_score() =>
+            {
+                Some(moon_shooter)
+            }
+            _ => None,
+        }
+    }
+
+    fn moon_rule_option(&self, moon_shooter: &Player) -> Option<MoonOption> {
+        self.notify(|observer| observer.display_moon_shot(moon_shooter, &self.config.moon_rule));
+        self.moon_rule_choice()
+    }
+
+    fn moon_rule_choice(&self) -> Option<MoonOption> {
+        match self.config.moon_rule {
+            MoonRule::AddToOthers => Some(MoonOption::AddToOthers),
+            MoonRule::SubtractFromSelf => Some(MoonOption::SubtractFromSelf),
+            MoonRule::PlayerChoice => None,
+        }
+    }
+
+    fn sun_shooter(&self, trick_winners: &[PlayerId]) -> Option<PlayerId> {
+        if self.config.sun_rule.is_none() {
+            return None;
+        }
+
+        let sun_shooter = *trick_winners.first()?;
+        trick_winners.iter().all(|winner| *winner == sun_shooter).then_some(sun_shooter)
+    }
+
+    fn sun_scores(&self, sun_shooter: PlayerId, sun_rule: SunRule) -> Vec<i16> {
+        let points = self.sun_points();
+        match sun_rule {
+            SunRule::AddToOthers => self
+                .players
+                .iter()
+                .map(|player| if player.id() == sun_shooter { 0 } else { points })
+                .collect(),
+            SunRule::SubtractFromSelf => self
+                .players
+                .iter()
+                .map(|player| if player.id() == sun_shooter { -points } else { 0 })
+                .collect(),
+        }
+    }
+
+    fn round_scores(
+        &self, scores: &[i16], sun_shooter: Option<PlayerId>, moon_option: Option<(PlayerId, MoonOption)>,
+    ) -> Vec<i16> {
+        let total_points = self.total_points();
+        match (sun_shooter, self.config.sun_rule, moon_option) {
+            (Some(sun_shooter), Some(sun_rule), _) => self.sun_scores(sun_shooter, sun_rule),
+            (_, _, Some((moon_shooter, moon_option))) => {
+                let jack_bonus = scores[moon_shooter.0] - total_points;
+                self.players
+                    .iter()
+                    .map(|player| match (player.id() == moon_shooter, moon_option) {
+                        (true, MoonOption::AddToOthers) => jack_bonus,
+                        (true, MoonOption::SubtractFromSelf) => jack_bonus - total_points,
+                        (false, MoonOption::AddToOthers) => total_points,
+                        (false, MoonOption::SubtractFromSelf) => 0,
+                    })
+                    .collect()
+            }
+            _ => scores.to_vec(),
+        }
+    }
+
+    fn finish_round(
+        &self, scores: Vec<i16>, trick_winners: Vec<PlayerId>, moon_option: Option<(PlayerId, MoonOption)>,
+    ) -> RoundResult {
+        let sun_shooter = self.sun_shooter(&trick_winners);
+        if let (Some(sun_shooter), Some(sun_rule)) = (sun_shooter, self.config.sun_rule) {
+            let points = self.sun_points();
+            self.notify(|observer| {
+                observer.display_sun_shot(&self.players[sun_shooter.0], &sun_rule, points)
+            });
+        }
+        let applied_scores = self.round_scores(&scores, sun_shooter, moon_option);
+
+        for (player, round_score) in zip(&self.players, &applied_scores) {
+            player.add_round_score(*round_score);
+        }
+
+        if let Some((moon_shooter, option)) = moon_option {
+            self.emit(GameEvent::MoonShot { player: self.players[moon_shooter.0].name.clone(), option });
+        }
+        self.notify(|observer| observer.display_scores(&self.players));
+        self.emit(GameEvent::RoundScored {
+            round: self.round_number,
+            scores: self.players.iter().map(|player| (player.name.clone(), player.score())).collect(),
+        });
+
+        RoundResult {
+            trick_points: scores,
+            applied_scores,
+            trick_winners,
+            tricks_won: self.players.iter().map(|player| player.tricks_won()).collect(),
+            moon_shooter: moon_option.map(|(moon_shooter, _)| moon_shooter),
+            sun_shooter,
+            max_score: self.max_score(),
+        }
+    }
+
+    /// Checks a planned round against the current hands without playing it. A play made out of turn
+    /// is reported as an illegal move by the player whose turn it was. Under `MoonRule::PlayerChoice`
+    /// no controller is asked, so a moon shooter is assumed to add to the other players' scores.
+    pub fn validate_round(&self, plays: &[(PlayerId, Card)]) -> GameResult<RoundResult> {
+        let mut hands = self.players.iter().map(|player| player.hand().clone()).collect_vec();
+        let mut starting_id = self
+            .players
+            .iter()
+            .find(|player| player.has_two_of_clubs())
+            .map(Player::id)
+            .ok_or(GameError::IncompleteRound)?;
+
+        let mut hearts_broken = false;
+        let mut scores = vec![0; self.players.len()];
+        let mut points_taken = vec![0; self.players.len()];
+        let mut trick_winners = vec![];
+        for trick in plays.chunks(self.players.len()) {
+            let is_first_turn = trick_winners.is_empty();
+            let mut table = vec![];
+            for (&(player_id, card), expected_id) in zip(trick, self.round_order(starting_id)) {
+                if player_id != expected_id {
+                    return Err(self.illegal_move(expected_id, card));
+                }
+                let hand = &mut hands[player_id.0];
+                let is_legal = !(is_first_turn && table.is_empty() && !card.is_two_of_clubs())
+                    && legal_moves(
+                        hand,
+                        &table,
+                        is_first_turn,
+                        hearts_broken,
+                        self.config.queen_allowed_first_trick,
+                    )
+                    .contains(&card);
+                if !is_legal {
+                    return Err(self.illegal_move(player_id, card));
+                }
+                hand.retain(|held| *held != card);
+                table.push((player_id, card));
+            }
+            if table.len() < self.players.len() {
+                return Err(GameError::IncompleteRound);
+            }
+
+            let (winner_id, _, score, points, breaks_hearts) = self.resolve_trick(&table);
+            hearts_broken |= breaks_hearts;
+            scores[winner_id.0] += score;
+            points_taken[winner_id.0] += points;
+            if is_first_turn {
+                let kitty = self.deck.kitty();
+                scores[winner_id.0] += kitty.iter().map(|card| self.card_score(card)).sum::<i16>();
+                points_taken[winner_id.0] +=
+                    kitty.iter().map(|card| u16::from(card.score_with(&self.config.scoring))).sum::<u16>();
+            }
+            trick_winners.push(winner_id);
+            starting_id = winner_id;
+        }
+        if hands.iter().any(|hand| !hand.is_empty()) {
+            return Err(GameError::IncompleteRound);
+        }
+
+        let sun_shooter = self.sun_shooter(&trick_winners);
+        let moon_shooter = self.find_moon_shooter(&scores, &points_taken).filter(|_| sun_shooter.is_none());
+        let moon_option =
+            moon_shooter.map(|id| (id, self.moon_rule_choice().unwrap_or(MoonOption::AddToOthers)));
+        let applied_scores = self.round_scores(&scores, sun_shooter, moon_option);
+
+        Ok(RoundResult {
+            tricks_won: self
+                .players
+                .iter()
+                .map(|player| trick_winners.iter().filter(|winner| **winner == player.id()).count() as u8)
+                .collect(),
+            max_score: zip(&self.players, &applied_scores)
+                .map(|(player, round_score)| player.score() + round_score)
+                .max()
+                .expect("At least one player should exist"),
+            trick_points: scores,
+            applied_scores,
+            trick_winners,
+            moon_shooter,
+            sun_shooter,
+        })
+    }
+
+    fn breaks_hearts(&self, card: &Card) -> bool {
+        card.is_hearts() || (self.config.queen_breaks_hearts && card.is_queen_of_spades())
+    }
+
+    fn card_score(&self, card: &Card) -> i16 {
+        if self.config.jack_of_diamonds && card.is_jack_of_diamonds() {
+            self.config.scoring.jack_of_diamonds_points
+        } else {
+            i16::from(card.score_with(&self.config.scoring))
+        }
+    }
+
+    fn moon_score(&self) -> i16 {
+        if self.config.jack_of_diamonds {
+            self.total_points() + self.config.scoring.jack_of_diamonds_points
+        } else {
+            self.total_points()
+        }
+    }
+
+    fn sun_points(&self) -> i16 {
+        2 * self.total_points()
+    }
+
+    fn total_points(&self) -> i16 {
+        i16::try_from(self.config.scoring.total_points()).expect("Round points should fit in a score")
+    }
+
+    fn preview_pass(&self, from_index: usize) -> Vec<Card> {
+        self.notify(|observer| observer.display_pass_preview(&self.players[from_index]));
+        self.players[from_index].suggested_pass(self.config.pass_count, self.config.allow_passing_queen)
+    }
+
+    fn check_pass(&self, from_index: usize, card_choices: &[Card]) -> GameResult<()> {
+        if card_choices.len() != self.config.pass_count {
+            return Err(GameError::PassCountError {
+                player: self.players[from_index].name.clone(),
+                expected: self.config.pass_count,
+                found: card_choices.len(),
+            });
+        }
+        if card_choices.iter().collect::<HashSet<_>>().len() != card_choices.len() {
+            return Err(GameError::DuplicatePass { player: self.players[from_index].name.clone() });
+        }
+        if !self.config.allow_passing_queen && card_choices.iter().any(Card::is_queen_of_spades) {
+            return Err(GameError::IllegalPass { player: self.players[from_index].name.clone() });
+        }
+        if !card_choices.iter().all(|card| self.players[from_index].hand().contains(card)) {
+            return Err(GameError::MissingPassCard { player: self.players[from_index].name.clone() });
+        }
+
+        Ok(())
+    }
+
+    fn exchange_passes(&self, selections: Vec<(usize, usize, Vec<Card>)>) {
+        let cards_to_pass = selections
+            .into_iter()
+            .map(|(from, to, card_choices)| {
+                let to_pass = self.players[from].pass(&card_choices).expect("Checked passes should be held");
+                (from, to, to_pass)
+            })
+            .collect();
+        self.receive_passes(cards_to_pass);
+    }
+
+    fn receive_passes(&self, cards_to_pass: Vec<(usize, usize, Vec<Card>)>) {
+        for (from, to, to_pass) in cards_to_pass {
+            self.emit(GameEvent::CardsPassed {
+                from: self.players[from].name.clone(),
+                to: self.players[to].name.clone(),
+                cards: to_pass.clone(),
+            });
+            self.notify(|observer| observer.display_received_cards(&self.players[to], &to_pass));
+            self.players[to].take(to_pass);
+        }
+    }
+
+    fn outcome(&self) -> GameOutcome {
+        self.announce_winners();
+
+        let winners = self.winner();
+        GameOutcome {
+            names: self.players.iter().map(|player| player.name.clone()).collect(),
+            scores: self.players.iter().map(|player| player.score()).collect(),
+            winners: winners.iter().map(|player| player.id()).collect(),
+            rounds: self.round_number,
+            round_scores: self.players.iter().map(|player| player.round_scores().clone()).collect(),
+        }
+    }
+
+    pub fn current_scores(&self) -> Vec<(String, i16)> {
+        self.players
+            .iter()
+            .map(|player| (player.name.clone(), player.score()))
+            .sorted_by(|(a_name, a_score), (b_name, b_score)| a_score.cmp(b_score).then(a_name.cmp(b_name)))
+            .collect()
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.max_score() >= self.config.target_score
+    }
+
+    pub fn winner(&self) -> Vec<&Player> {
+        if !self.is_over() {
+            return vec![];
+        }
+
+        let min_score =
+            self.players.iter().map(|player| player.score()).min().expect("At least one player should exist");
+        self.players
+            .iter()
+            .filter(|player| player.score() == min_score)
+            .sorted_by_key(|player| player.id())
+            .collect()
+    }
+
+    pub fn announce_winners(&self) {
+        self.notify(|observer| observer.display_game_over(&self.winner()));
+        self.emit(GameEvent::GameOver {
+            winners: self.winner().iter().map(|player| player.name.clone()).collect(),
+        });
+    }
+
+    fn max_score(&self) -> i16 {
+        self.players.iter().map(|player| player.score()).max().expect("At least one player should exist")
+    }
+
+    fn round_order(&self, starting_id: PlayerId) -> Vec<PlayerId> {
+        (0..self.players.len()).map(|i| PlayerId((i + starting_id.0) % self.players.len())).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, rc::Rc};
+
+    use super::*;
+    use crate::models::{
+        card::{Rank, Suit},
+        config::ScoringRules,
+        controller::{RandomController, ScriptedController},
+        deck::fixtures::{suited_deck, suited_tricks, SUITS},
+    };
+
+    fn cards(shorthand: &str) -> Vec<Card> {
+        shorthand.split_whitespace().map(|card| card.parse().unwrap()).collect()
+    }
+
+    fn names(count: usize) -> Vec<String> {
+        (1..=count).map(|i| format!("Player {i}")).collect()
+    }
+
+    fn suited_game(config: GameConfig) -> Game<RandomController> {
+        let mut game =
+            Game::with_names(config, suited_deck(), RandomController::with_seed(0), names(4)).unwrap();
+        game.deck.deal(&game.players).unwrap();
+        game
+    }
+
+    fn suited_plays() -> Vec<(PlayerId, Card)> {
+        suited_tricks()
+            .into_iter()
+            .flat_map(|trick| trick.into_iter().enumerate().map(|(i, card)| (PlayerId(i), card)))
+            .collect()
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        moon_shots: RefCell<Vec<String>>,
+        sun_shots: RefCell<Vec<(String, i16)>>,
+        hands_shown: RefCell<Vec<PlayerId>>,
+        tables: RefCell<Vec<Vec<(PlayerId, Card)>>>,
+        trick_winners: RefCell<Vec<String>>,
+        game_starts: RefCell<Vec<(GameConfig, usize)>>,
+        forced_moves: RefCell<Vec<Card>>,
+        events: RefCell<Vec<GameEvent>>,
+        received: RefCell<Vec<(PlayerId, Vec<Card>)>>,
+    }
+
+    impl Observer for Recorder {
+        fn display_received_cards(&self, player: &Player, received: &[Card]) {
+            self.received.borrow_mut().push((player.id(), received.to_vec()));
+        }
+
+        fn on_event(&self, event: &GameEvent) {
+            self.events.borrow_mut().push(event.clone());
+        }
+
+        fn display_forced_move(&self, _player: &Player, card: Card) {
+            self.forced_moves.borrow_mut().push(card);
+        }
+
+        fn display_game_start(&self, config: &GameConfig, players: &[Player]) {
+            self.game_starts.borrow_mut().push((*config, players.len()));
+        }
+
+        fn display_winner(&self, player: &Player, _card: Card, _score: i16) {
+            self.trick_winners.borrow_mut().push(player.name.clone());
+        }
+
+        fn display_table(&self, _players: &[Player], table: &[(PlayerId, Card)]) {
+            self.tables.borrow_mut().push(table.to_vec());
+        }
+
+        fn display_hand(&self, player: &Player) {
+            self.hands_shown.borrow_mut().push(player.id());
+        }
+
+        fn display_moon_shot(&self, shooter: &Player, _rule: &MoonRule) {
+            self.moon_shots.borrow_mut().push(shooter.name.clone());
+        }
+
+        fn display_sun_shot(&self, shooter: &Player, _rule: &SunRule, points: i16) {
+            self.sun_shots.borrow_mut().push((shooter.name.clone(), points));
+        }
+    }
+
+    fn scripted_game(config: GameConfig, deck: Deck, placements: Vec<Card>) -> Game<ScriptedController> {
+        let controller = ScriptedController::new(names(config.player_count), vec![], placements);
+        Game::with_names(config, deck, controller, names(config.player_count)).unwrap()
+    }
+
+    const SPLIT_POINTS_PLACEMENTS: &str =
+        "2C QS 2S 3C 2D AC 2H AD 3D 4C 3S 3H 4D 5C 4S 4H 5D 6C 5S 5H 6D 7C 6S 6H 7D 8C 7S 7H \
+         8D 9C 8S 8H 9D 10C 9S 9H 10D JC 10S 10H JD QC JS JH QD KC KS QH KD AH AS KH";
+
+    fn split_points_game() -> Game<ScriptedController> {
+        split_points_game_with(cards(SPLIT_POINTS_PLACEMENTS))
+    }
+
+    fn split_points_game_with(placements: Vec<Card>) -> Game<ScriptedController> {
+        let config = GameConfig::builder().no_passing(true).queen_allowed_first_trick(true).build().unwrap();
+        let deck = Deck::stacked(cards(
+            "2S 3D 4D 5D 6D 7D 8D 9D 10D JD QD KD AD \
+             3C 4C 5C 6C 7C 8C 9C 10C JC QC KC 2D AH \
+             2C AC 3S 4S 5S 6S 7S 8S 9S 10S JS KS AS \
+             QS 2H 3H 4H 5H 6H 7H 8H 9H 10H JH QH KH",
+        ));
+        scripted_game(config, deck, placements)
+    }
+
+    #[test]
+    fn split_hearts_and_queen_are_not_a_moon() {
+        let mut game = split_points_game();
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_points, vec![13, 13, 0, 0]);
+        assert_eq!(result.applied_scores, vec![13, 13, 0, 0]);
+        assert_eq!(result.moon_shooter, None);
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![13, 13, 0, 0]);
+    }
+
+    #[test]
+    fn taking_every_point_is_a_moon() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_points, vec![26, 0, 0, 0]);
+        assert_eq!(result.applied_scores, vec![0, 26, 26, 26]);
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![0, 26, 26, 26]);
+    }
+
+    #[test]
+    fn validates_legal_plan() {
+        let game = suited_game(GameConfig::default());
+        let result = game.validate_round(&suited_plays()).unwrap();
+
+        assert_eq!(result.trick_points, vec![26, 0, 0, 0]);
+        assert_eq!(result.applied_scores, vec![0, 26, 26, 26]);
+        assert_eq!(result.tricks_won, vec![13, 0, 0, 0]);
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(result.max_score, 26);
+        assert!(game.players().iter().all(|player| player.hand().len() == 13));
+    }
+
+    #[test]
+    fn validates_player_choice_moon_as_adding_to_others() {
+        let game = suited_game(GameConfig::builder().moon_rule(MoonRule::PlayerChoice).build().unwrap());
+        let result = game.validate_round(&suited_plays()).unwrap();
+
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(result.max_score, 26);
+    }
+
+    #[test]
+    fn moon_shooter_keeps_jack_of_diamonds_bonus() {
+        let config = GameConfig::builder().jack_of_diamonds(true).no_passing(true).build().unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_points, vec![16, 0, 0, 0]);
+        assert_eq!(result.applied_scores, vec![-10, 26, 26, 26]);
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![-10, 26, 26, 26]);
+        assert_eq!(
+            game.round_scores(&result.trick_points, None, Some((PlayerId(0), MoonOption::SubtractFromSelf))),
+            vec![-36, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn rejects_illegal_plan() {
+        let game = suited_game(GameConfig::default());
+
+        let mut out_of_turn = suited_plays();
+        out_of_turn.swap(0, 1);
+        assert!(matches!(game.validate_round(&out_of_turn), Err(GameError::IllegalMove { .. })));
+
+        let mut unknown_player = suited_plays();
+        unknown_player[0].0 = PlayerId(9);
+        assert!(matches!(game.validate_round(&unknown_player), Err(GameError::IllegalMove { .. })));
+
+        let incomplete = &suited_plays()[..8];
+        assert!(matches!(game.validate_round(incomplete), Err(GameError::IncompleteRound)));
+    }
+
+    #[test]
+    fn no_passing_keeps_dealt_hands() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game =
+            Game::with_names(config, suited_deck(), RandomController::with_seed(0), names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+        game.pass_cards(&passing_order).unwrap();
+
+        assert!(matches!(passing_order, PassingOrder::Hold));
+        for (player, suit) in zip(game.players(), SUITS) {
+            assert!(player.hand().iter().all(|card| card.suit == suit));
+        }
+    }
+
+    #[test]
+    fn passing_order_cycles_through_saves() {
+        let config = GameConfig::builder().seed(5).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(0)).unwrap();
+        let passing_orders = (0..5).map(|_| game.start_round().unwrap()).collect_vec();
+
+        assert!(matches!(
+            passing_orders[..],
+            [
+                PassingOrder::Right,
+                PassingOrder::Across,
+                PassingOrder::Left,
+                PassingOrder::Hold,
+                PassingOrder::Right
+            ]
+        ));
+
+        let mut loaded = Game::load(game.save(), RandomController::with_seed(0)).unwrap();
+        assert!(matches!(loaded.start_round().unwrap(), PassingOrder::Across));
+    }
+
+    #[test]
+    fn save_and_load_round_trip() {
+        let config = GameConfig::builder().no_passing(true).seed(3).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(0)).unwrap();
+        game.round().unwrap();
+        game.round().unwrap();
+
+        let state = game.save();
+        let loaded = Game::load(state.clone(), RandomController::with_seed(0)).unwrap();
+
+        assert_eq!(loaded.round_number(), 2);
+        assert_eq!(loaded.current_scores(), game.current_scores());
+        assert_eq!(loaded.save(), state);
+    }
+
+    #[test]
+    fn player_ids_are_stable_across_rounds() {
+        let config = GameConfig::builder().seed(7).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(0)).unwrap();
+        let names: Vec<String> = game.players().iter().map(|player| player.name.clone()).collect();
+
+        for _ in 0..3 {
+            game.round().unwrap();
+            for (i, name) in names.iter().enumerate() {
+                let player = game.player(PlayerId(i));
+                assert_eq!(player.id(), PlayerId(i));
+                assert_eq!(&player.name, name);
+                assert_eq!(game.players()[i].id(), PlayerId(i));
+            }
+        }
+    }
+
+    #[test]
+    fn legal_moves_follow_each_rule() {
+        let hand = cards("2C 9C 4D KH");
+        assert_eq!(legal_moves(&hand, &[], true, false, false), cards("2C"));
+
+        let table = [(PlayerId(1), Card::new(Rank::Five, Suit::Clubs))];
+        assert_eq!(legal_moves(&hand, &table, false, false, false), cards("2C 9C"));
+
+        let table = [(PlayerId(1), Card::new(Rank::Five, Suit::Spades))];
+        assert_eq!(legal_moves(&hand, &table, false, false, false), hand);
+
+        assert_eq!(legal_moves(&hand, &[], false, false, false), cards("2C 9C 4D"));
+        assert_eq!(legal_moves(&hand, &[], false, true, false), hand);
+    }
+
+    #[test]
+    fn legal_moves_allow_leading_hearts_from_an_all_heart_hand() {
+        let hand = cards("2H 7H KH");
+
+        assert_eq!(legal_moves(&hand, &[], false, false, false), hand);
+    }
+
+    #[test]
+    fn point_cards_wait_until_after_the_first_trick() {
+        let table = [(PlayerId(0), Card::new(Rank::Two, Suit::Clubs))];
+
+        assert_eq!(legal_moves(&cards("QS 4D KH"), &table, true, false, false), cards("4D"));
+        assert_eq!(legal_moves(&cards("QS 4D KH"), &table, false, false, false), cards("QS 4D KH"));
+        assert_eq!(legal_moves(&cards("3H 9H"), &table, true, false, false), cards("3H 9H"));
+    }
+
+    #[test]
+    fn lower_target_ends_the_game_sooner() {
+        let play = |target_score| {
+            let config = GameConfig::builder().target_score(target_score).seed(13).build().unwrap();
+            let mut game = Game::new(config, RandomController::with_seed(13)).unwrap();
+            assert_eq!(game.target_score(), target_score);
+            game.play().unwrap()
+        };
+
+        let short = play(10);
+        let long = play(100);
+
+        assert!(short.scores.iter().any(|score| *score >= 10));
+        assert!(short.rounds < long.rounds);
+    }
+
+    #[test]
+    fn moon_rules_adjust_final_scores() {
+        let moon_scores = |moon_rule, moon_options| {
+            let config = GameConfig::builder().no_passing(true).moon_rule(moon_rule).build().unwrap();
+            let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+            let controller =
+                ScriptedController::new(names(4), vec![], placements).with_moon_options(moon_options);
+            let mut game = Game::with_names(config, suited_deck(), controller, names(4)).unwrap();
+            game.round().unwrap();
+            game.players().iter().map(Player::score).collect_vec()
+        };
+
+        assert_eq!(moon_scores(MoonRule::AddToOthers, vec![]), vec![0, 26, 26, 26]);
+        assert_eq!(moon_scores(MoonRule::SubtractFromSelf, vec![]), vec![-26, 0, 0, 0]);
+        assert_eq!(
+            moon_scores(MoonRule::PlayerChoice, vec![MoonOption::SubtractFromSelf]),
+            vec![-26, 0, 0, 0]
+        );
+        assert_eq!(moon_scores(MoonRule::PlayerChoice, vec![MoonOption::AddToOthers]), vec![0, 26, 26, 26]);
+    }
+
+    #[test]
+    fn jack_of_diamonds_can_leave_a_negative_round_score() {
+        let config = GameConfig::builder().no_passing(true).jack_of_diamonds(true).build().unwrap();
+        let deck = Deck::stacked(cards(
+            "2C 4C 5C 6C 7C 8C 9C 10C JC QC KC 2D JD \
+             AC 3C 3D 4D 5D 6D 7D 8D 9D 10D QD KD AD \
+             2S 3S 4S 5S 6S 7S 8S 9S 10S JS QS KS AS \
+             2H 3H 4H 5H 6H 7H 8H 9H 10H JH QH KH AH",
+        ));
+        let placements = cards(
+            "2C AC 2S 2H AD 3S 3H JD 3C 4S 4H 4C 5C 3D 5S 5H 6C 4D 6S 6H 7C 5D 7S 7H 8C 6D 8S 8H \
+             9C 7D 9S 9H 10C 8D 10S 10H JC 9D JS JH QC 10D QS QH KC QD KS KH 2D KD AS AH",
+        );
+        let mut game = scripted_game(config, deck, placements);
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_points, vec![23, -7, 0, 0]);
+        assert_eq!(result.moon_shooter, None);
+        assert_eq!(game.player(PlayerId(1)).score(), -7);
+    }
+
+    #[test]
+    fn loading_a_save_restores_hands_and_scores() {
+        let config = GameConfig::builder().seed(18).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(18)).unwrap();
+        game.round().unwrap();
+        game.start_round().unwrap();
+
+        let loaded = Game::load(game.save(), RandomController::with_seed(18)).unwrap();
+
+        for (original, restored) in zip(game.players(), loaded.players()) {
+            assert_eq!(restored.name, original.name);
+            assert_eq!(*restored.hand(), *original.hand());
+            assert_eq!(restored.score(), original.score());
+            assert_eq!(*restored.round_scores(), *original.round_scores());
+        }
+        assert_eq!(loaded.target_score(), game.target_score());
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn saved_games_round_trip_through_json() {
+        let config = GameConfig::builder().seed(18).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(18)).unwrap();
+        game.round().unwrap();
+        game.start_round().unwrap();
+
+        let state = game.save();
+        let json = serde_json::to_string(&state).unwrap();
+
+        assert_eq!(serde_json::from_str::<GameState>(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn round_result_describes_a_scripted_round() {
+        let mut game = split_points_game();
+
+        let result = game.round().unwrap();
+
+        assert_eq!(
+            result,
+            RoundResult {
+                trick_points: vec![13, 13, 0, 0],
+                applied_scores: vec![13, 13, 0, 0],
+                trick_winners: [vec![PlayerId(1)], vec![PlayerId(0); 12]].concat(),
+                tricks_won: vec![12, 1, 0, 0],
+                moon_shooter: None,
+                sun_shooter: None,
+                max_score: 13,
+            }
+        );
+    }
+
+    #[test]
+    fn round_history_records_each_delta() {
+        let config =
+            GameConfig::builder().no_passing(true).moon_rule(MoonRule::SubtractFromSelf).build().unwrap();
+        let placements = suited_plays().into_iter().chain(suited_plays()).map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+
+        game.round().unwrap();
+        game.round().unwrap();
+
+        let shooter = game.player(PlayerId(0));
+        assert_eq!(*shooter.round_scores(), vec![-26, -26]);
+        assert_eq!(shooter.round_delta(0), None);
+        assert_eq!(shooter.round_delta(1), Some(-26));
+        assert_eq!(shooter.round_delta(game.round_number()), Some(-26));
+        assert_eq!(shooter.round_delta(3), None);
+        assert_eq!(shooter.score(), -52);
+        for player in &game.players()[1..] {
+            assert_eq!(*player.round_scores(), vec![0, 0]);
+        }
+    }
+
+    fn game_with_scores(scores: &[i16]) -> Game<RandomController> {
+        let config = GameConfig::builder().player_count(scores.len()).build().unwrap();
+        let game = Game::with_names(config, Deck::new(), RandomController::with_seed(0), names(scores.len()))
+            .unwrap();
+        for (player, score) in zip(game.players(), scores) {
+            player.add_score(*score);
+        }
+        game
+    }
+
+    #[test]
+    fn lowest_score_wins_once_the_game_is_over() {
+        assert!(game_with_scores(&[90, 20, 30, 40]).winner().is_empty());
+
+        let game = game_with_scores(&[100, 20, 30, 40]);
+        assert!(game.is_over());
+        assert_eq!(game.winner().iter().map(|player| player.id()).collect_vec(), vec![PlayerId(1)]);
+    }
+
+    #[test]
+    fn tied_low_scores_share_the_win() {
+        let game = game_with_scores(&[104, 35, 60, 35]);
+
+        assert_eq!(
+            game.winner().iter().map(|player| player.id()).collect_vec(),
+            vec![PlayerId(1), PlayerId(3)]
+        );
+    }
+
+    #[test]
+    fn moon_shot_is_announced_once() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        assert_eq!(*recorder.moon_shots.borrow(), vec!["Player 1".to_owned()]);
+    }
+
+    #[test]
+    fn illegal_placements_are_rejected() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+
+        let mut game = scripted_game(config, suited_deck(), cards("3C"));
+        assert!(matches!(
+            game.round(),
+            Err(GameError::IllegalMove { player, card }) if player == "Player 1" && card == cards("3C")[0]
+        ));
+
+        let mut game = scripted_game(config, suited_deck(), cards("2C 3H"));
+        assert!(matches!(
+            game.round(),
+            Err(GameError::IllegalMove { player, card }) if player == "Player 2" && card == cards("3H")[0]
+        ));
+    }
+
+    #[test]
+    fn tricks_won_add_up_to_thirteen() {
+        let mut game = split_points_game();
+
+        let result = game.round().unwrap();
+        let tricks_won = game.players().iter().map(Player::tricks_won).collect_vec();
+
+        assert_eq!(tricks_won, vec![12, 1, 0, 0]);
+        assert_eq!(tricks_won, result.tricks_won);
+        assert_eq!(tricks_won.iter().map(|tricks| usize::from(*tricks)).sum::<usize>(), 13);
+        assert_eq!(result.trick_winners.len(), 13);
+    }
+
+    #[test]
+    fn duplicate_or_empty_names_are_rejected() {
+        let start = |names: &[&str]| {
+            let names = names.iter().map(|name| name.to_string()).collect_vec();
+            Game::new(GameConfig::default(), ScriptedController::new(names, vec![], vec![]))
+        };
+
+        assert!(matches!(start(&["Ann", "Bo", "ann ", "Cy"]), Err(GameError::StartError)));
+        assert!(matches!(start(&["Ann", "Bo", "  ", "Cy"]), Err(GameError::StartError)));
+        assert!(start(&["Ann", "Bo", "Cy", "Di"]).is_ok());
+    }
+
+    #[test]
+    fn play_stops_at_a_low_target_with_the_expected_winner() {
+        let config = GameConfig::builder().no_passing(true).target_score(20).build().unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+
+        let outcome = game.play().unwrap();
+
+        assert_eq!(outcome.rounds, 1);
+        assert_eq!(outcome.scores, vec![0, 26, 26, 26]);
+        assert_eq!(outcome.winners, vec![PlayerId(0)]);
+    }
+
+    #[test]
+    fn passing_two_cards_keeps_hands_full() {
+        let player = Player::restore(PlayerId(0), "Player 1".to_owned(), cards("2C 3C 4C 5C"), 0, vec![]);
+        assert_eq!(player.pass(&cards("2C 5C")), Some(cards("2C 5C")));
+        assert_eq!(*player.hand(), cards("3C 4C"));
+
+        let config = GameConfig::builder().pass_count(2).build().unwrap();
+        let passes =
+            SUITS.iter().map(|suit| vec![Card::new(Rank::Two, *suit), Card::new(Rank::Three, *suit)]);
+        let controller = ScriptedController::new(names(4), passes.collect(), vec![]);
+        let mut game = Game::with_names(config, suited_deck(), controller, names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+        game.pass_cards(&passing_order).unwrap();
+
+        assert!(matches!(passing_order, PassingOrder::Right));
+        assert!(game.players().iter().all(|player| player.hand().len() == 13));
+        assert_eq!(game.player(PlayerId(0)).cards_of_suit(Suit::Clubs).len(), 11);
+        assert_eq!(game.player(PlayerId(3)).cards_of_suit(Suit::Clubs), cards("2C 3C"));
+    }
+
+    fn dealt_game(config: GameConfig, hands: &str) -> Game<RandomController> {
+        let mut game =
+            Game::with_names(config, Deck::stacked(cards(hands)), RandomController::with_seed(0), names(4))
+                .unwrap();
+        game.deck.deal(&game.players).unwrap();
+        game
+    }
+
+    fn plays(shorthand: &str) -> Vec<(PlayerId, Card)> {
+        shorthand
+            .split_whitespace()
+            .map(|play| {
+                let (player, card) = play.split_once(':').unwrap();
+                (PlayerId(player.parse().unwrap()), card.parse().unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn queen_of_spades_can_break_hearts() {
+        let hands = "2C AS 2H 2D 3D 4D 5D 6D 7D 8D 9D 10D JD \
+                     3C QS 3H 4H 5H 6H 7H 8H 9H 10H JH QH KH \
+                     4C 5C 6C 7C 8C 9C 10C JC QC KC AC QD 3S \
+                     2S 4S 5S 6S 7S 8S 9S 10S JS KS AD AH KD";
+        let queen_then_heart_lead = plays("0:2C 1:3C 2:AC 3:2S 2:3S 3:4S 0:AS 1:QS 0:2H");
+
+        let game = dealt_game(GameConfig::builder().queen_breaks_hearts(true).build().unwrap(), hands);
+        assert!(matches!(game.validate_round(&queen_then_heart_lead), Err(GameError::IncompleteRound)));
+
+        let game = dealt_game(GameConfig::default(), hands);
+        assert!(matches!(
+            game.validate_round(&queen_then_heart_lead),
+            Err(GameError::IllegalMove { player, card }) if player == "Player 1" && card == cards("2H")[0]
+        ));
+    }
+
+    #[test]
+    fn hearts_may_only_be_led_once_broken() {
+        let hands = "2C AS 2H 2D 3D 4D 5D 6D 7D 8D 9D 10D JD \
+                     3C KD 3H 4H 5H 6H 7H 8H 9H 10H JH QH KH \
+                     4C 5C 6C 7C 8C 9C 10C JC QC KC AC QD 3S \
+                     2S 4S 5S 6S 7S 8S 9S 10S JS KS QS AD AH";
+        let game = dealt_game(GameConfig::default(), hands);
+
+        let unbroken_lead = plays("0:2C 1:3C 2:AC 3:2S 2:3S 3:4S 0:AS 1:KD 0:2H");
+        assert!(matches!(
+            game.validate_round(&unbroken_lead),
+            Err(GameError::IllegalMove { player, .. }) if player == "Player 1"
+        ));
+
+        let broken_lead = plays("0:2C 1:3C 2:AC 3:2S 2:3S 3:4S 0:AS 1:3H 0:2H");
+        assert!(matches!(game.validate_round(&broken_lead), Err(GameError::IncompleteRound)));
+    }
+
+    #[test]
+    fn only_a_full_tally_shoots_the_moon() {
+        let game = game_with_scores(&[0, 0, 0, 0]);
+
+        assert_eq!(game.find_moon_shooter(&[0, 26, 0, 0], &[0, 26, 0, 0]), Some(PlayerId(1)));
+        assert_eq!(game.find_moon_shooter(&[6, 13, 4, 3], &[6, 13, 4, 3]), None);
+        assert_eq!(game.find_moon_shooter(&[13, 13, 0, 0], &[13, 13, 0, 0]), None);
+        assert_eq!(game.find_moon_shooter(&[0, 0, 0, 0], &[0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn each_turn_shows_the_player_their_hand() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let plays = suited_plays();
+        let mut game = scripted_game(config, suited_deck(), plays.iter().map(|(_, card)| *card).collect());
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        assert_eq!(*recorder.hands_shown.borrow(), plays.iter().map(|(id, _)| *id).collect_vec());
+    }
+
+    #[test]
+    fn passing_the_queen_can_be_banned() {
+        let config = GameConfig::builder().allow_passing_queen(false).build().unwrap();
+        let passes = vec![cards("2C 3C 4C"), cards("2D 3D 4D"), cards("QS 2S 3S")];
+        let controller = ScriptedController::new(names(4), passes, vec![]);
+        let mut game = Game::with_names(config, suited_deck(), controller, names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+
+        assert!(matches!(
+            game.pass_cards(&passing_order),
+            Err(GameError::IllegalPass { player }) if player == "Player 3"
+        ));
+        assert!(game.player(PlayerId(2)).hand().iter().any(Card::is_queen_of_spades));
+    }
+
+    #[test]
+    fn new_round_clears_leftover_cards() {
+        let mut game = suited_game(GameConfig::builder().no_passing(true).build().unwrap());
+        game.player(PlayerId(0)).take(cards("AH"));
+        assert_eq!(game.player(PlayerId(0)).hand().len(), 14);
+
+        game.start_round().unwrap();
+
+        assert!(game.players().iter().all(|player| player.hand().len() == 13));
+        assert!(!game.player(PlayerId(0)).hand().contains(&cards("AH")[0]));
+    }
+
+    #[test]
+    fn table_grows_through_each_trick() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let plays = suited_plays();
+        let mut game = scripted_game(config, suited_deck(), plays.iter().map(|(_, card)| *card).collect());
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        let tables = recorder.tables.borrow();
+        assert_eq!(tables.len(), 52);
+        for (trick_tables, trick) in zip(tables.chunks(4), plays.chunks(4)) {
+            for (seen, table) in trick_tables.iter().enumerate() {
+                assert_eq!(*table, trick[..seen]);
+            }
+        }
+    }
+
+    #[test]
+    fn every_observer_hears_about_trick_winners() {
+        let mut game = split_points_game();
+        let (first, second) = (Rc::new(Recorder::default()), Rc::new(Recorder::default()));
+        game.add_observer(Box::new(first.clone()));
+        game.add_observer(Box::new(second.clone()));
+
+        game.round().unwrap();
+
+        let expected = [vec!["Player 2".to_owned()], vec!["Player 1".to_owned(); 12]].concat();
+        assert_eq!(*first.trick_winners.borrow(), expected);
+        assert_eq!(*second.trick_winners.borrow(), expected);
+    }
+
+    #[test]
+    fn points_taken_match_round_scores() {
+        let mut game = split_points_game();
+
+        let result = game.round().unwrap();
+        let points_taken = game.players().iter().map(Player::points_taken).collect_vec();
+
+        assert_eq!(points_taken, vec![13, 13, 0, 0]);
+        assert_eq!(points_taken, result.trick_points.iter().map(|score| *score as u16).collect_vec());
+    }
+
+    #[test]
+    fn first_trick_must_open_with_the_two_of_clubs() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = scripted_game(config, suited_deck(), cards("5C"));
+
+        assert!(matches!(
+            game.round(),
+            Err(GameError::IllegalMove { player, card }) if player == "Player 1" && card == cards("5C")[0]
+        ));
+    }
+
+    #[test]
+    fn errors_carry_their_context() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let error = scripted_game(config, suited_deck(), cards("2C 2H")).round().unwrap_err();
+        assert!(matches!(&error, GameError::IllegalMove { card, .. } if *card == cards("2H")[0]));
+        assert_eq!(error.to_string(), "Player 2 cannot play the Two of Hearts.");
+
+        let error = scripted_game(config, suited_deck(), vec![]).round().unwrap_err();
+        assert!(matches!(error, GameError::TurnError(ControllerError::Failed)));
+        assert!(std::error::Error::source(&error).is_some());
+    }
+
+    #[test]
+    fn first_trick_winner_takes_the_kitty() {
+        let config = GameConfig::builder().player_count(6).no_passing(true).build().unwrap();
+        let deck = Deck::stacked(cards(
+            "2C 8C 9C 10C JC QC KC AC \
+             3C 4C 5C 6C 7C 2D 3D 4D \
+             5D 6D 7D 8D 9D 10D JD QD \
+             KD AD 2S 3S 4S 5S 6S 7S \
+             8S 9S 10S JS QS KS AS 2H \
+             3H 4H 5H 6H 7H 8H 9H 10H \
+             JH QH KH AH",
+        ));
+        let placements = cards(
+            "2C 3C 5D KD 8S 3H \
+             4C 6D AD 9S 4H 8C \
+             9C 5C 7D 2S 10S 5H \
+             10C 6C 8D 3S JS 6H \
+             JC 7C 9D 4S QS 7H \
+             QC 2D 10D 5S KS 8H \
+             KC 3D JD 6S AS 9H \
+             AC 4D QD 7S 2H 10H",
+        );
+        let mut game = scripted_game(config, deck, placements);
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_winners, [vec![PlayerId(1)], vec![PlayerId(0); 7]].concat());
+        assert_eq!(result.trick_points, vec![21, 5, 0, 0, 0, 0]);
+        assert_eq!(game.player(PlayerId(1)).points_taken(), 5);
+        assert!(game.deck.kitty().is_empty());
+    }
+
+    #[test]
+    fn passing_indices_wrap_for_any_table_size() {
+        assert_eq!(passing_indices(&PassingOrder::Left, 3), Some(vec![(0, 1), (1, 2), (2, 0)]));
+        assert_eq!(passing_indices(&PassingOrder::Right, 3), Some(vec![(0, 2), (1, 0), (2, 1)]));
+        assert_eq!(passing_indices(&PassingOrder::Across, 3), None);
+
+        assert_eq!(passing_indices(&PassingOrder::Left, 4), Some(vec![(0, 1), (1, 2), (2, 3), (3, 0)]));
+        assert_eq!(passing_indices(&PassingOrder::Right, 4), Some(vec![(0, 3), (1, 0), (2, 1), (3, 2)]));
+        assert_eq!(passing_indices(&PassingOrder::Across, 4), Some(vec![(0, 2), (1, 3), (2, 0), (3, 1)]));
+
+        assert_eq!(
+            passing_indices(&PassingOrder::Left, 5),
+            Some(vec![(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)])
+        );
+        assert_eq!(
+            passing_indices(&PassingOrder::Right, 5),
+            Some(vec![(0, 4), (1, 0), (2, 1), (3, 2), (4, 3)])
+        );
+        assert_eq!(passing_indices(&PassingOrder::Hold, 5), None);
+    }
+
+    #[test]
+    fn sweeping_every_trick_shoots_the_sun() {
+        let sun_round = |sun_rule| {
+            let config = GameConfig::builder().no_passing(true).sun_rule(sun_rule).build().unwrap();
+            let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+            let mut game = scripted_game(config, suited_deck(), placements);
+            let result = game.round().unwrap();
+            (result, game.players().iter().map(Player::score).collect_vec())
+        };
+
+        let (result, scores) = sun_round(SunRule::AddToOthers);
+        assert_eq!(result.sun_shooter, Some(PlayerId(0)));
+        assert_eq!(result.moon_shooter, None);
+        assert_eq!(result.tricks_won, vec![13, 0, 0, 0]);
+        assert_eq!(scores, vec![0, 52, 52, 52]);
+
+        let (result, scores) = sun_round(SunRule::SubtractFromSelf);
+        assert_eq!(result.sun_shooter, Some(PlayerId(0)));
+        assert_eq!(scores, vec![-52, 0, 0, 0]);
+    }
+
+    #[test]
+    fn round_counter_increments_each_round() {
+        let config = GameConfig::builder().seed(66).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(66)).unwrap();
+        assert_eq!(game.round_number(), 0);
+
+        for round in 1..=3 {
+            game.round().unwrap();
+            assert_eq!(game.round_number(), round);
+        }
+    }
+
+    #[test]
+    fn duplicate_pass_cards_are_rejected() {
+        let controller = ScriptedController::new(names(4), vec![cards("2C 2C 3C")], vec![]);
+        let mut game = Game::with_names(GameConfig::default(), suited_deck(), controller, names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+
+        assert!(matches!(
+            game.pass_cards(&passing_order),
+            Err(GameError::DuplicatePass { player }) if player == "Player 1"
+        ));
+        assert_eq!(game.player(PlayerId(0)).hand().len(), 13);
+    }
+
+    #[test]
+    fn game_start_is_announced_once() {
+        let config = GameConfig::builder().seed(68).target_score(50).jack_of_diamonds(true).build().unwrap();
+        let mut game = Game::new(config, RandomController::with_seed(68)).unwrap();
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+        game.round().unwrap();
+
+        assert_eq!(*recorder.game_starts.borrow(), vec![(config, 4)]);
+    }
+
+    #[test]
+    fn tracker_reports_the_cards_left_after_a_few_tricks() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = scripted_game(config, suited_deck(), cards("2C 2D 2S 2H 3C 3D 3S 3H"));
+
+        assert!(matches!(game.round(), Err(GameError::TurnError(_))));
+
+        let played = game.played();
+        assert_eq!(played.played(), cards("2C 3C 2D 3D 2S 3S 2H 3H"));
+        assert_eq!(played.remaining().len(), 44);
+        assert_eq!(played.remaining_in_suit(Suit::Hearts), cards("4H 5H 6H 7H 8H 9H 10H JH QH KH AH"));
+        assert!(played.is_played(&cards("3S")[0]));
+        assert!(!played.is_played(&cards("4S")[0]));
+    }
+
+    #[test]
+    fn ace_low_tricks_go_to_the_king() {
+        let game = suited_game(GameConfig::builder().rank_order(RankOrder::AceLow).build().unwrap());
+        let trick =
+            [(PlayerId(0), cards("AS")[0]), (PlayerId(1), cards("KS")[0]), (PlayerId(2), cards("2S")[0])];
+
+        let (winner_id, winning_card, ..) = game.resolve_trick(&trick);
+
+        assert_eq!(winner_id, PlayerId(1));
+        assert_eq!(winning_card, cards("KS")[0]);
+    }
+
+    #[test]
+    fn current_scores_are_sorted_after_a_round() {
+        let mut game = split_points_game();
+
+        game.round().unwrap();
+
+        assert_eq!(
+            game.current_scores(),
+            vec![
+                ("Player 3".to_owned(), 0),
+                ("Player 4".to_owned(), 0),
+                ("Player 1".to_owned(), 13),
+                ("Player 2".to_owned(), 13),
+            ]
+        );
+    }
+
+    #[test]
+    fn queen_on_the_first_trick_follows_the_config() {
+        let club_void = cards("QS 4D KH");
+        let table = [(PlayerId(0), Card::new(Rank::Two, Suit::Clubs))];
+
+        assert_eq!(legal_moves(&club_void, &table, true, false, false), cards("4D"));
+        assert_eq!(legal_moves(&club_void, &table, true, false, true), cards("QS 4D"));
+        assert_eq!(legal_moves(&cards("QS KH"), &table, true, false, false), cards("QS KH"));
+        assert_eq!(legal_moves(&cards("QS KH"), &table, true, false, true), cards("QS"));
+    }
+
+    #[cfg(feature = "json")]
+    #[test]
+    fn outcomes_round_trip_through_json() {
+        let outcome = GameOutcome {
+            names: names(4),
+            scores: vec![104, 35, 60, 35],
+            winners: vec![PlayerId(1), PlayerId(3)],
+            rounds: 2,
+            round_scores: vec![vec![78, 26], vec![0, 35], vec![26, 34], vec![0, 35]],
+        };
+
+        let json = outcome.to_json();
+
+        assert!(json.contains("\"scores\":[104,35,60,35]"));
+        assert_eq!(serde_json::from_str::<GameOutcome>(&json).unwrap(), outcome);
+    }
+
+    #[test]
+    fn passing_too_few_cards_is_rejected() {
+        let controller = ScriptedController::new(names(4), vec![cards("2C 3C")], vec![]);
+        let mut game = Game::with_names(GameConfig::default(), suited_deck(), controller, names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+
+        assert!(matches!(
+            game.pass_cards(&passing_order),
+            Err(GameError::PassCountError { player, expected: 3, found: 2 }) if player == "Player 1"
+        ));
+        assert_eq!(game.player(PlayerId(0)).hand().len(), 13);
+    }
+
+    #[test]
+    fn tied_winners_are_ordered_by_player_id() {
+        let game = game_with_scores(&[20, 110, 20, 45]);
+
+        assert_eq!(game.max_score(), 110);
+        assert_eq!(
+            game.winner().iter().map(|player| player.id()).collect_vec(),
+            vec![PlayerId(0), PlayerId(2)]
+        );
+        assert_eq!(game.outcome().winners, vec![PlayerId(0), PlayerId(2)]);
+    }
+
+    #[test]
+    fn forced_moves_are_played_without_a_prompt() {
+        let config = GameConfig::builder().no_passing(true).auto_play_forced(true).build().unwrap();
+        let plays = suited_plays().into_iter().map(|(_, card)| card).collect_vec();
+        let mut game = scripted_game(config, suited_deck(), plays[1..48].to_vec());
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        assert_eq!(*recorder.forced_moves.borrow(), cards("2C AC AD AS AH"));
+        assert_eq!(recorder.hands_shown.borrow().len(), 47);
+    }
+
+    #[test]
+    fn scripted_round_emits_each_event_in_order() {
+        let mut game = split_points_game();
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        let names = names(4);
+        let mut expected = vec![];
+        let placements = cards(SPLIT_POINTS_PLACEMENTS);
+        for (i, trick) in placements.chunks(4).enumerate() {
+            let (leader, winner) = match i {
+                0 => (2, 1),
+                1 => (1, 0),
+                _ => (0, 0),
+            };
+            for (offset, card) in trick.iter().enumerate() {
+                expected.push(GameEvent::CardPlaced {
+                    player: names[(leader + offset) % 4].clone(),
+                    card: *card,
+                });
+            }
+            expected.push(GameEvent::TrickWon {
+                player: names[winner].clone(),
+                card: *trick.iter().filter(|card| card.suit == trick[0].suit).max().unwrap(),
+                score: trick.iter().map(|card| i16::from(card.score())).sum(),
+            });
+        }
+        expected.push(GameEvent::RoundScored {
+            round: 1,
+            scores: names.into_iter().zip([13, 13, 0, 0]).collect(),
+        });
+
+        assert_eq!(*recorder.events.borrow(), expected);
+    }
+
+    #[test]
+    fn suggested_cards_are_always_legal() {
+        let hand = cards("2C 9C QS 4D 7H KH");
+        let tables = [
+            vec![],
+            vec![(PlayerId(1), cards("5C")[0])],
+            vec![(PlayerId(1), cards("10C")[0]), (PlayerId(2), cards("3C")[0])],
+            vec![(PlayerId(1), cards("5S")[0])],
+            vec![(PlayerId(1), cards("AD")[0])],
+            vec![(PlayerId(1), cards("2H")[0])],
+        ];
+
+        for table in &tables {
+            for (is_first_trick, hearts_broken) in [(true, false), (false, false), (false, true)] {
+                let moves = legal_moves(&hand, table, is_first_trick, hearts_broken, false);
+                let suggestion =
+                    suggest_card(&hand, table, is_first_trick, hearts_broken, false, RankOrder::AceHigh)
+                        .unwrap();
+                assert!(moves.contains(&suggestion));
+            }
+        }
+
+        assert_eq!(
+            suggest_card(&hand, &tables[2], false, false, false, RankOrder::AceHigh),
+            cards("9C").first().copied()
+        );
+    }
+
+    #[test]
+    fn suggestions_follow_an_ace_low_rank_order() {
+        let hand = cards("AC KC 3D");
+        let table = plays("1:10C 2:QC");
+
+        assert_eq!(suggest_card(&hand, &table, false, true, false, RankOrder::AceHigh), Some(cards("KC")[0]));
+        assert_eq!(suggest_card(&hand, &table, false, true, false, RankOrder::AceLow), Some(cards("AC")[0]));
+        assert_eq!(suggest_card(&hand, &[], false, true, false, RankOrder::AceHigh), Some(cards("3D")[0]));
+        assert_eq!(suggest_card(&hand, &[], false, true, false, RankOrder::AceLow), Some(cards("AC")[0]));
+    }
+
+    #[test]
+    fn players_are_told_which_cards_they_received() {
+        let passes = vec![cards("2C 3C 4C"), cards("2D 3D 4D"), cards("2S 3S 4S"), cards("2H 3H 4H")];
+        let controller = ScriptedController::new(names(4), passes, vec![]);
+        let mut game = Game::with_names(GameConfig::default(), suited_deck(), controller, names(4)).unwrap();
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        let passing_order = game.start_round().unwrap();
+        game.pass_cards(&passing_order).unwrap();
+
+        assert_eq!(
+            *recorder.received.borrow(),
+            vec![
+                (PlayerId(3), cards("2C 3C 4C")),
+                (PlayerId(0), cards("2D 3D 4D")),
+                (PlayerId(1), cards("2S 3S 4S")),
+                (PlayerId(2), cards("2H 3H 4H")),
+            ]
+        );
+        for (id, received) in recorder.received.borrow().iter() {
+            assert!(received.iter().all(|card| game.player(*id).hand().contains(card)));
+        }
+    }
+
+    #[test]
+    fn resuming_after_five_tricks_matches_an_uninterrupted_round() {
+        let uninterrupted = split_points_game().round().unwrap();
+
+        let placements = cards(SPLIT_POINTS_PLACEMENTS);
+        let mut interrupted = split_points_game_with(placements[..20].to_vec());
+        assert!(matches!(interrupted.round(), Err(GameError::TurnError(_))));
+        let state = interrupted.save();
+        assert_eq!(state.round.as_ref().map(|round| round.trick_winners.len()), Some(5));
+
+        let controller = ScriptedController::new(names(4), vec![], placements[20..].to_vec());
+        let mut resumed = Game::load(state, controller).unwrap();
+        let result = resumed.round().unwrap();
+
+        assert_eq!(result, uninterrupted);
+        assert_eq!(resumed.players().iter().map(Player::score).collect_vec(), vec![13, 13, 0, 0]);
+    }
+
+    #[test]
+    fn defaults_give_a_full_deck_and_four_players() {
+        let deck = Deck::default();
+        assert_eq!(deck.len(), 52);
+
+        let game = Game::with_controller(RandomController::with_seed(0)).unwrap();
+        assert_eq!(game.players().len(), 4);
+        assert_eq!(game.deck.len(), 52);
+        assert_eq!(game.target_score(), GameConfig::default().target_score);
+    }
+
+    #[test]
+    fn a_queen_worth_twenty_five_raises_the_round_total() {
+        let scoring = ScoringRules { queen_points: 25, ..ScoringRules::default() };
+        let config = GameConfig::builder().no_passing(true).scoring(scoring).build().unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+
+        let result = game.round().unwrap();
+
+        assert_eq!(result.trick_points, vec![38, 0, 0, 0]);
+        assert_eq!(result.applied_scores, vec![0, 38, 38, 38]);
+        assert_eq!(result.moon_shooter, Some(PlayerId(0)));
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![0, 38, 38, 38]);
+    }
+
+    #[test]
+    fn sun_shot_reports_the_total_from_the_scoring_rules() {
+        let scoring = ScoringRules { queen_points: 25, ..ScoringRules::default() };
+        let config = GameConfig::builder()
+            .no_passing(true)
+            .scoring(scoring)
+            .sun_rule(SunRule::AddToOthers)
+            .build()
+            .unwrap();
+        let placements = suited_plays().into_iter().map(|(_, card)| card).collect();
+        let mut game = scripted_game(config, suited_deck(), placements);
+        let recorder = Rc::new(Recorder::default());
+        game.add_observer(Box::new(recorder.clone()));
+
+        game.round().unwrap();
+
+        assert_eq!(*recorder.sun_shots.borrow(), vec![("Player 1".to_owned(), 76)]);
+        assert_eq!(game.players().iter().map(Player::score).collect_vec(), vec![0, 76, 76, 76]);
+    }
+
+    #[test]
+    fn last_trick_is_kept_once_the_next_trick_begins() {
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = scripted_game(config, suited_deck(), cards("2C 2D 2S 2H 3C"));
+
+        assert!(game.last_trick().is_none());
+        assert!(matches!(game.round(), Err(GameError::TurnError(_))));
+
+        let (trick, winner_id) = game.last_trick().unwrap();
+        assert_eq!(trick, plays("0:2C 1:2D 2:2S 3:2H"));
+        assert_eq!(winner_id, PlayerId(0));
+        assert_eq!(game.played().played(), cards("2C 3C 2D 2S 2H"));
+    }
+
+    #[test]
+    fn passing_an_unheld_card_is_rejected() {
+        let controller = ScriptedController::new(names(4), vec![cards("2C 3C 2H")], vec![]);
+        let mut game = Game::with_names(GameConfig::default(), suited_deck(), controller, names(4)).unwrap();
+
+        let passing_order = game.start_round().unwrap();
+
+        assert!(matches!(
+            game.pass_cards(&passing_order),
+            Err(GameError::MissingPassCard { player }) if player == "Player 1"
+        ));
+        assert_eq!(game.player(PlayerId(0)).hand().len(), 13);
+    }
+
+    #[test]
+    fn left_passing_is_described_with_player_names() {
+        let players = |names: &[&str]| {
+            names.iter().enumerate().map(|(i, name)| Player::new(PlayerId(i), name.to_string())).collect_vec()
+        };
+
+        assert_eq!(
+            PassingOrder::Left.describe(&players(&["Alice", "Bob", "Carol"])),
+            "Alice → Bob, Bob → Carol, Carol → Alice"
+        );
+        assert_eq!(
+            PassingOrder::Left.describe(&players(&["Alice", "Bob", "Carol", "Dave", "Erin"])),
+            "Alice → Bob, Bob → Carol, Carol → Dave, Dave → Erin, Erin → Alice"
+        );
+        assert_eq!(PassingOrder::Across.describe(&players(&["Alice", "Bob", "Carol"])), "Hold");
+    }
+
+    #[test]
+    fn players_reflect_the_configured_names() {
+        let config = GameConfig::builder().player_count(5).build().unwrap();
+        let names = ["Alice", "Bob", " Carol ", "Dave", "Erin"].map(str::to_owned).to_vec();
+        let game =
+            Game::with_names(config, Deck::for_players(5).unwrap(), RandomController::with_seed(98), names)
+                .unwrap();
+
+        let players = game.players();
+
+        assert_eq!(players.len(), 5);
+        assert_eq!(
+            players.iter().map(|player| player.name.as_str()).collect_vec(),
+            ["Alice", "Bob", "Carol", "Dave", "Erin"]
+        );
+        assert_eq!(players.iter().map(Player::id).collect_vec(), (0..5).map(PlayerId).collect_vec());
+        assert!(players.iter().all(|player| player.score() == 0));
+        assert_eq!(game.player(PlayerId(2)), &players[2]);
+    }
+
+    #[test]
+    fn moon_status_follows_the_point_tricks() {
+        let placements = cards(SPLIT_POINTS_PLACEMENTS);
+        let status_after = |plays: usize| {
+            let mut game = split_points_game_with(placements[..plays].to_vec());
+            assert!(matches!(game.round(), Err(GameError::TurnError(_))));
+            game.moon_status()
+        };
+
+        assert_eq!(split_points_game().moon_status(), MoonStatus::NoPointsTaken);
+        assert_eq!(status_after(0), MoonStatus::NoPointsTaken);
+        assert_eq!(status_after(4), MoonStatus::OnePlayer(PlayerId(1)));
+        assert_eq!(status_after(8), MoonStatus::ManyPlayers);
+    }
+
+    #[test]
+    fn a_banned_last_pass_leaves_every_hand_intact() {
+        let mut dealt = suited_deck().cards().to_vec();
+        dealt.swap(36, 39);
+        let config = GameConfig::builder().allow_passing_queen(false).build().unwrap();
+        let passes = vec![cards("2C 3C 4C"), cards("2D 3D 4D"), cards("2S 3S 4S"), cards("QS 3H 4H")];
+        let controller = ScriptedController::new(names(4), passes, vec![]);
+        let mut game = Game::with_names(config, Deck::stacked(dealt.clone()), controller, names(4)).unwrap();
+
+        assert!(matches!(game.round(), Err(GameError::IllegalPass { player }) if player == "Player 4"));
+        for (player, hand) in zip(game.players(), dealt.chunks(13)) {
+            assert_eq!(*player.hand(), hand);
+        }
+        assert_eq!(game.round_number(), 0);
+    }
+
+    #[test]
+    fn a_rejected_last_pass_leaves_every_hand_intact() {
+        let last_passes: [(&str, fn(&GameError) -> bool); 3] = [
+            ("2H 3H", |error| matches!(error, GameError::PassCountError { expected: 3, found: 2, .. })),
+            ("2H 2H 3H", |error| matches!(error, GameError::DuplicatePass { .. })),
+            ("2H 3H 2C", |error| matches!(error, GameError::MissingPassCard { .. })),
+        ];
+
+        for (last_pass, expected) in last_passes {
+            let passes = vec![cards("2C 3C 4C"), cards("2D 3D 4D"), cards("2S 3S 4S"), cards(last_pass)];
+            let controller = ScriptedController::new(names(4), passes, vec![]);
+            let mut game =
+                Game::with_names(GameConfig::default(), suited_deck(), controller, names(4)).unwrap();
+
+            let error = game.round().unwrap_err();
+            assert!(expected(&error), "{last_pass}: {error:?}");
+            for (player, hand) in zip(game.players(), suited_deck().cards().chunks(13)) {
+                assert_eq!(*player.hand(), hand);
+            }
+            assert_eq!(game.round_number(), 0);
+        }
     }
 }
@@ -0,0 +1,204 @@
+use std::{
+    cell::RefCell,
+    io::{BufRead, BufReader, Write},
+    net::{TcpListener, TcpStream},
+};
+
+use serde::{de::DeserializeOwned, Serialize};
+
+use super::{
+    card::Card,
+    controller::{Controller, ControllerError},
+    game::{MoonOption, PassingOrder},
+    observer::Observer,
+    player::{Player, PlayerId},
+};
+
+type ControllerResult<T> = Result<T, ControllerError>;
+
+#[derive(Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum Message<'a> {
+    Names {
+        count: usize,
+    },
+    Pass {
+        player: &'a str,
+        to: &'a str,
+        direction: &'static str,
+        hand: Vec<Card>,
+        count: usize,
+        allow_queen: bool,
+        suggested: &'a [Card],
+    },
+    Place {
+        player: &'a str,
+        hand: Vec<Card>,
+        table: &'a [(PlayerId, Card)],
+        legal_moves: Vec<Card>,
+    },
+    MoonOption {
+        player: &'a str,
+    },
+    TrickWon {
+        player: &'a str,
+        card: Card,
+        score: i16,
+    },
+    Scores {
+        scores: Vec<(&'a str, i16)>,
+    },
+    GameOver {
+        winners: Vec<&'a str>,
+    },
+}
+
+pub struct NetworkController {
+    reader: RefCell<BufReader<TcpStream>>,
+    writer: RefCell<TcpStream>,
+}
+
+impl NetworkController {
+    pub fn new(stream: TcpStream) -> std::io::Result<Self> {
+        Ok(Self { reader: RefCell::new(BufReader::new(stream.try_clone()?)), writer: RefCell::new(stream) })
+    }
+
+    pub fn accept(listener: &TcpListener) -> std::io::Result<Self> {
+        let (stream, _) = listener.accept()?;
+        Self::new(stream)
+    }
+
+    fn send(&self, message: &Message) -> ControllerResult<()> {
+        let mut writer = self.writer.borrow_mut();
+        serde_json::to_writer(&mut *writer, message).map_err(|_| ControllerError::Failed)?;
+        writer.write_all(b"\n").and_then(|_| writer.flush()).map_err(|_| ControllerError::Failed)
+    }
+
+    fn request<T: DeserializeOwned>(&self, message: &Message) -> ControllerResult<T> {
+        self.send(message)?;
+
+        let mut line = String::new();
+        match self.reader.borrow_mut().read_line(&mut line) {
+            Ok(0) | Err(_) => Err(ControllerError::Failed),
+            Ok(_) => serde_json::from_str(&line).map_err(|_| ControllerError::Failed),
+        }
+    }
+}
+
+impl Controller for NetworkController {
+    fn get_names(&self, count: usize) -> ControllerResult<Vec<String>> {
+        self.request(&Message::Names { count })
+    }
+
+    fn get_cards_to_pass(
+        &self, from: &Player, to: &Player, passing_order: &PassingOrder, count: usize, allow_queen: bool,
+        suggested: &[Card],
+    ) -> ControllerResult<Vec<Card>> {
+        self.request(&Message::Pass {
+            player: &from.name,
+            to: &to.name,
+            direction: passing_order.direction(),
+            hand: from.sorted_hand(),
+            count,
+            allow_queen,
+            suggested,
+        })
+    }
+
+    fn get_card_to_place(
+        &self, player: &Player, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> ControllerResult<Card> {
+        self.request(&Message::Place {
+            player: &player.name,
+            hand: player.sorted_hand(),
+            table,
+            legal_moves: player.legal_moves(table, is_first_trick, hearts_broken, queen_allowed_first_trick),
+        })
+    }
+
+    fn choose_moon_option(&self, shooter: &Player) -> ControllerResult<MoonOption> {
+        self.request(&Message::MoonOption { player: &shooter.name })
+    }
+}
+
+impl Observer for NetworkController {
+    fn display_winner(&self, player: &Player, card: Card, score: i16) {
+        let _ = self.send(&Message::TrickWon { player: &player.name, card, score });
+    }
+
+    fn display_scores(&self, players: &[Player]) {
+        let scores = players.iter().map(|player| (player.name.as_str(), player.score())).collect();
+        let _ = self.send(&Message::Scores { scores });
+    }
+
+    fn display_game_over(&self, winners: &[&Player]) {
+        let winners = winners.iter().map(|player| player.name.as_str()).collect();
+        let _ = self.send(&Message::GameOver { winners });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{net::SocketAddr, thread};
+
+    use serde_json::{json, Value};
+
+    use super::*;
+    use crate::models::{
+        config::GameConfig,
+        deck::fixtures::suited_deck,
+        game::{Game, GameError},
+    };
+
+    fn client(address: SocketAddr) -> Vec<String> {
+        let stream = TcpStream::connect(address).unwrap();
+        let mut writer = stream.try_clone().unwrap();
+        let mut trick_winners = vec![];
+        for line in BufReader::new(stream).lines() {
+            let message: Value = serde_json::from_str(&line.unwrap()).unwrap();
+            let reply = match message["type"].as_str().unwrap() {
+                "names" => json!(["Ann", "Bo", "Cy", "Di"]),
+                "place" => message["legal_moves"][0].clone(),
+                "trick_won" => {
+                    trick_winners.push(message["player"].as_str().unwrap().to_owned());
+                    continue;
+                }
+                _ => continue,
+            };
+            writeln!(writer, "{reply}").unwrap();
+        }
+        trick_winners
+    }
+
+    #[test]
+    fn plays_a_round_with_a_loopback_client() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = thread::spawn(move || client(address));
+
+        let controller = NetworkController::accept(&listener).unwrap();
+        let config = GameConfig::builder().no_passing(true).build().unwrap();
+        let mut game = Game::new(config, controller).unwrap().with_deck(suited_deck());
+        let result = game.round().unwrap();
+        drop(game);
+
+        assert_eq!(result.tricks_won, vec![13, 0, 0, 0]);
+        assert_eq!(client.join().unwrap(), vec!["Ann".to_owned(); 13]);
+    }
+
+    #[test]
+    fn disconnected_client_fails_the_controller() {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let client = thread::spawn(move || drop(TcpStream::connect(address).unwrap()));
+
+        let controller = NetworkController::accept(&listener).unwrap();
+        client.join().unwrap();
+
+        assert!(matches!(
+            Game::new(GameConfig::default(), controller),
+            Err(GameError::NamesError(ControllerError::Failed))
+        ));
+    }
+}
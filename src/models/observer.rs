@@ -0,0 +1,222 @@
+use std::{
+    cell::{Cell, RefCell},
+    fmt::Write,
+    rc::Rc,
+};
+
+use iter_tools::Itertools;
+
+use super::{
+    card::Card,
+    config::{GameConfig, MoonRule, SunRule},
+    events::GameEvent,
+    game::PassingOrder,
+    player::{Player, PlayerId},
+};
+
+pub trait Observer {
+    fn on_event(&self, _event: &GameEvent) {}
+
+    fn display_game_start(&self, _config: &GameConfig, _players: &[Player]) {}
+
+    fn display_passing_order(&self, _passing_order: &PassingOrder, _players: &[Player]) {}
+
+    fn display_round_start(&self, _round: usize) {}
+
+    fn display_pass_preview(&self, _player: &Player) {}
+
+    fn display_received_cards(&self, _player: &Player, _received: &[Card]) {}
+
+    fn display_hand(&self, _player: &Player) {}
+
+    fn display_forced_move(&self, _player: &Player, _card: Card) {}
+
+    fn display_table(&self, _players: &[Player], _table: &[(PlayerId, Card)]) {}
+
+    fn display_last_trick(&self, _players: &[Player], _trick: &[(PlayerId, Card)], _winner: &Player) {}
+
+    fn display_winner(&self, _player: &Player, _card: Card, _score: i16) {}
+
+    fn display_moon_shot(&self, _shooter: &Player, _rule: &MoonRule) {}
+
+    fn display_sun_shot(&self, _shooter: &Player, _rule: &SunRule, _points: i16) {}
+
+    fn display_scores(&self, _players: &[Player]) {}
+
+    fn display_game_over(&self, _winners: &[&Player]) {}
+
+    fn display_match_standings(&self, _names: &[String], _wins: &[usize]) {}
+}
+
+impl<T: Observer + ?Sized> Observer for Rc<T> {
+    fn on_event(&self, event: &GameEvent) {
+        self.as_ref().on_event(event);
+    }
+
+    fn display_game_start(&self, config: &GameConfig, players: &[Player]) {
+        self.as_ref().display_game_start(config, players);
+    }
+
+    fn display_passing_order(&self, passing_order: &PassingOrder, players: &[Player]) {
+        self.as_ref().display_passing_order(passing_order, players);
+    }
+
+    fn display_round_start(&self, round: usize) {
+        self.as_ref().display_round_start(round);
+    }
+
+    fn display_pass_preview(&self, player: &Player) {
+        self.as_ref().display_pass_preview(player);
+    }
+
+    fn display_received_cards(&self, player: &Player, received: &[Card]) {
+        self.as_ref().display_received_cards(player, received);
+    }
+
+    fn display_hand(&self, player: &Player) {
+        self.as_ref().display_hand(player);
+    }
+
+    fn display_forced_move(&self, player: &Player, card: Card) {
+        self.as_ref().display_forced_move(player, card);
+    }
+
+    fn display_table(&self, players: &[Player], table: &[(PlayerId, Card)]) {
+        self.as_ref().display_table(players, table);
+    }
+
+    fn display_last_trick(&self, players: &[Player], trick: &[(PlayerId, Card)], winner: &Player) {
+        self.as_ref().display_last_trick(players, trick, winner);
+    }
+
+    fn display_winner(&self, player: &Player, card: Card, score: i16) {
+        self.as_ref().display_winner(player, card, score);
+    }
+
+    fn display_moon_shot(&self, shooter: &Player, rule: &MoonRule) {
+        self.as_ref().display_moon_shot(shooter, rule);
+    }
+
+    fn display_sun_shot(&self, shooter: &Player, rule: &SunRule, points: i16) {
+        self.as_ref().display_sun_shot(shooter, rule, points);
+    }
+
+    fn display_scores(&self, players: &[Player]) {
+        self.as_ref().display_scores(players);
+    }
+
+    fn display_game_over(&self, winners: &[&Player]) {
+        self.as_ref().display_game_over(winners);
+    }
+
+    fn display_match_standings(&self, names: &[String], wins: &[usize]) {
+        self.as_ref().display_match_standings(names, wins);
+    }
+}
+
+#[derive(Default)]
+pub struct TextRenderer {
+    round: Cell<usize>,
+    passing_order: RefCell<Option<String>>,
+    players: RefCell<Vec<(String, usize, i16)>>,
+    table: RefCell<Vec<(PlayerId, Card)>>,
+}
+
+impl TextRenderer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn render(&self) -> String {
+        let players = self.players.borrow();
+        let mut output = String::new();
+
+        let _ = writeln!(output, "Round {}", self.round.get());
+        if let Some(passing_order) = self.passing_order.borrow().as_ref() {
+            let _ = writeln!(output, "Passing order: {passing_order}");
+        }
+
+        let table = self.table.borrow();
+        if table.is_empty() {
+            let _ = writeln!(output, "Table: empty");
+        } else {
+            let _ = writeln!(
+                output,
+                "Table: {}",
+                table.iter().map(|(i, card)| format!("{} played {card:#}", players[i.0].0)).join(", ")
+            );
+        }
+
+        for (name, hand_size, score) in players.iter() {
+            let _ = writeln!(output, "{name}: {hand_size} cards, {score} points");
+        }
+
+        output
+    }
+
+    pub fn print(&self) {
+        print!("{}", self.render());
+    }
+
+    fn snapshot(&self, players: &[Player]) {
+        *self.players.borrow_mut() =
+            players.iter().map(|player| (player.name.clone(), player.hand().len(), player.score())).collect();
+    }
+}
+
+impl Observer for TextRenderer {
+    fn display_passing_order(&self, passing_order: &PassingOrder, players: &[Player]) {
+        *self.passing_order.borrow_mut() = Some(passing_order.describe(players));
+    }
+
+    fn display_round_start(&self, round: usize) {
+        self.round.set(round);
+        self.table.borrow_mut().clear();
+    }
+
+    fn display_table(&self, players: &[Player], table: &[(PlayerId, Card)]) {
+        self.snapshot(players);
+        *self.table.borrow_mut() = table.to_vec();
+    }
+
+    fn display_last_trick(&self, _players: &[Player], _trick: &[(PlayerId, Card)], _winner: &Player) {}
+
+    fn display_winner(&self, _player: &Player, _card: Card, _score: i16) {
+        self.table.borrow_mut().clear();
+    }
+
+    fn display_scores(&self, players: &[Player]) {
+        self.snapshot(players);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card;
+
+    #[test]
+    fn text_renderer_prints_the_game_state() {
+        let players = vec![
+            Player::restore(PlayerId(0), "Ann".to_owned(), vec![card!(3, C), card!(K, H)], 12, vec![]),
+            Player::restore(PlayerId(1), "Bo".to_owned(), vec![card!(9, D)], 4, vec![]),
+            Player::restore(PlayerId(2), "Cy".to_owned(), vec![], 0, vec![]),
+        ];
+        let renderer = TextRenderer::new();
+
+        renderer.display_round_start(2);
+        renderer.display_passing_order(&PassingOrder::Left, &players);
+        renderer.display_table(&players, &[(PlayerId(1), card!(2, C))]);
+        let output = renderer.render();
+
+        assert!(output.contains("Round 2"));
+        assert!(output.contains(&format!("Passing order: {}", PassingOrder::Left.describe(&players))));
+        assert!(output.contains("Table: Bo played 2♣"));
+        assert!(output.contains("Ann: 2 cards, 12 points"));
+        assert!(output.contains("Bo: 1 cards, 4 points"));
+        assert!(output.contains("Cy: 0 cards, 0 points"));
+
+        renderer.display_winner(&players[1], card!(2, C), 0);
+        assert!(renderer.render().contains("Table: empty"));
+    }
+}
@@ -1,50 +1,194 @@
 use std::{
     cell::{Cell, Ref, RefCell},
+    collections::HashSet,
     fmt::Display,
 };
 
-use super::card::Card;
+use super::{
+    card::{Card, RankOrder, Suit},
+    game::{legal_moves, suggest_card},
+};
 use derivative::Derivative;
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerId(pub usize);
+
 #[derive(Debug, Derivative)]
-#[derivative(PartialEq, Eq, PartialOrd, Ord)]
+#[derivative(PartialEq, Eq)]
 pub struct Player {
-    #[derivative(PartialOrd = "ignore")]
-    #[derivative(Ord = "ignore")]
+    id: PlayerId,
+
     pub name: String,
 
-    #[derivative(PartialOrd = "ignore")]
-    #[derivative(Ord = "ignore")]
+    #[derivative(PartialEq = "ignore")]
     hand: RefCell<Vec<Card>>,
 
-    score: Cell<u8>,
+    #[derivative(PartialEq = "ignore")]
+    score: Cell<i16>,
+
+    #[derivative(PartialEq = "ignore")]
+    round_scores: RefCell<Vec<i16>>,
+
+    #[derivative(PartialEq = "ignore")]
+    tricks_won: Cell<u8>,
+
+    #[derivative(PartialEq = "ignore")]
+    points_taken: Cell<u16>,
+
+    #[derivative(PartialEq = "ignore")]
+    last_placed: Cell<Option<(usize, Card)>>,
+}
+
+pub fn are_valid_names(names: &[String]) -> bool {
+    let mut seen_names = HashSet::new();
+    names
+        .iter()
+        .map(|name| name.trim())
+        .all(|name| !name.is_empty() && seen_names.insert(name.to_lowercase()))
 }
 
 impl Player {
-    pub fn new(name: String) -> Self {
-        Player { name, hand: RefCell::new(vec![]), score: Cell::new(0) }
+    pub fn new(id: PlayerId, name: String) -> Self {
+        Self::restore(id, name, vec![], 0, vec![])
+    }
+
+    pub fn restore(id: PlayerId, name: String, hand: Vec<Card>, score: i16, round_scores: Vec<i16>) -> Self {
+        Player {
+            id,
+            name,
+            hand: RefCell::new(hand),
+            score: Cell::new(score),
+            round_scores: RefCell::new(round_scores),
+            tricks_won: Cell::new(0),
+            points_taken: Cell::new(0),
+            last_placed: Cell::new(None),
+        }
+    }
+
+    pub fn id(&self) -> PlayerId {
+        self.id
     }
 
     pub fn hand(&self) -> Ref<Vec<Card>> {
         self.hand.borrow()
     }
 
-    pub fn score(&self) -> u8 {
+    pub fn sorted_hand(&self) -> Vec<Card> {
+        let mut hand = self.hand.borrow().clone();
+        hand.sort_by(Card::cmp_by_suit_then_rank);
+        hand
+    }
+
+    pub fn hand_indexed(&self) -> Vec<(usize, Card)> {
+        self.sorted_hand().into_iter().enumerate().collect()
+    }
+
+    pub fn score(&self) -> i16 {
         self.score.get()
     }
 
-    pub fn add_score(&self, score: u8) {
+    pub fn add_score(&self, score: i16) {
         self.score.set(self.score.get() + score);
     }
 
+    pub fn round_scores(&self) -> Ref<Vec<i16>> {
+        self.round_scores.borrow()
+    }
+
+    pub fn round_delta(&self, round: usize) -> Option<i16> {
+        self.round_scores.borrow().get(round.checked_sub(1)?).copied()
+    }
+
+    pub fn add_round_score(&self, score: i16) {
+        self.round_scores.borrow_mut().push(score);
+        self.add_score(score);
+    }
+
+    pub fn tricks_won(&self) -> u8 {
+        self.tricks_won.get()
+    }
+
+    pub fn points_taken(&self) -> u16 {
+        self.points_taken.get()
+    }
+
+    pub fn take_points(&self, points: u16) {
+        self.points_taken.set(self.points_taken.get() + points);
+    }
+
+    pub fn win_trick(&self, points: u16) {
+        self.tricks_won.set(self.tricks_won.get() + 1);
+        self.take_points(points);
+    }
+
+    pub fn restore_tricks(&self, tricks_won: u8, points_taken: u16) {
+        self.tricks_won.set(tricks_won);
+        self.points_taken.set(points_taken);
+    }
+
+    pub fn reset_tricks(&self) {
+        self.tricks_won.set(0);
+        self.points_taken.set(0);
+    }
+
     pub fn has_two_of_clubs(&self) -> bool {
         self.hand.borrow().iter().any(|card| card.is_two_of_clubs())
     }
 
-    pub fn pass(&self, choices: &[Card]) -> Vec<Card> {
+    pub fn has_suit(&self, suit: Suit) -> bool {
+        self.hand.borrow().iter().any(|card| card.suit == suit)
+    }
+
+    pub fn is_void(&self, suit: Suit) -> bool {
+        !self.has_suit(suit)
+    }
+
+    pub fn cards_of_suit(&self, suit: Suit) -> Vec<Card> {
+        self.hand.borrow().iter().filter(|card| card.suit == suit).copied().collect()
+    }
+
+    pub fn legal_moves(
+        &self, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool,
+    ) -> Vec<Card> {
+        legal_moves(&self.hand.borrow(), table, is_first_trick, hearts_broken, queen_allowed_first_trick)
+    }
+
+    pub fn suggested_card(
+        &self, table: &[(PlayerId, Card)], is_first_trick: bool, hearts_broken: bool,
+        queen_allowed_first_trick: bool, rank_order: RankOrder,
+    ) -> Option<Card> {
+        suggest_card(
+            &self.hand.borrow(),
+            table,
+            is_first_trick,
+            hearts_broken,
+            queen_allowed_first_trick,
+            rank_order,
+        )
+    }
+
+    pub fn suggested_pass(&self, count: usize, allow_queen: bool) -> Vec<Card> {
+        let mut hand = self.hand.borrow().clone();
+        hand.retain(|card| allow_queen || !card.is_queen_of_spades());
+        hand.sort_by(|a, b| b.cmp(a));
+        hand.into_iter().take(count).collect()
+    }
+
+    pub fn pass(&self, choices: &[Card]) -> Option<Vec<Card>> {
+        if !choices.iter().all(|card| self.hand.borrow().contains(card)) {
+            return None;
+        }
+
+        let choices: HashSet<&Card> = choices.iter().collect();
         let (to_pass, to_keep) = self.hand.borrow().iter().partition(|card| choices.contains(card));
         self.hand.swap(&RefCell::new(to_keep));
-        to_pass
+        Some(to_pass)
+    }
+
+    pub fn clear_hand(&self) {
+        self.hand.borrow_mut().clear();
     }
 
     pub fn take(&self, cards: Vec<Card>) {
@@ -53,7 +197,19 @@ impl Player {
 
     pub fn place(&self, choice: &Card) -> Option<Card> {
         let position = self.hand.borrow().iter().position(|card| card == choice)?;
-        Some(self.hand.borrow_mut().remove(position))
+        let placed_card = self.hand.borrow_mut().remove(position);
+        self.last_placed.set(Some((position, placed_card)));
+        Some(placed_card)
+    }
+
+    pub fn undo_place(&self, card: Card) {
+        let mut hand = self.hand.borrow_mut();
+        match self.last_placed.take() {
+            Some((position, last_card)) if last_card == card && position <= hand.len() => {
+                hand.insert(position, card)
+            }
+            _ => hand.push(card),
+        }
     }
 }
 
@@ -62,3 +218,119 @@ impl Display for Player {
         write!(f, "{}", self.name)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::card;
+
+    #[test]
+    fn players_with_equal_scores_are_distinct() {
+        let alice = Player::new(PlayerId(0), "Alice".to_owned());
+        let bob = Player::new(PlayerId(1), "Bob".to_owned());
+        alice.add_score(13);
+        bob.add_score(13);
+
+        assert_ne!(alice, bob);
+        assert_eq!(alice, Player::restore(PlayerId(0), "Alice".to_owned(), vec![], 0, vec![]));
+    }
+
+    #[test]
+    fn scores_accumulate_past_a_byte() {
+        let player = Player::new(PlayerId(0), "Alice".to_owned());
+        for _ in 0..12 {
+            player.add_round_score(26);
+        }
+
+        assert_eq!(player.score(), 312);
+        assert_eq!(player.round_scores().len(), 12);
+    }
+
+    #[test]
+    fn reports_suits_in_hand() {
+        let player = Player::restore(
+            PlayerId(0),
+            "Alice".to_owned(),
+            vec![card!(2, C), card!(K, H), card!(9, C)],
+            0,
+            vec![],
+        );
+
+        assert!(player.has_suit(Suit::Clubs));
+        assert!(player.is_void(Suit::Spades));
+        assert!(!player.is_void(Suit::Hearts));
+        assert_eq!(player.cards_of_suit(Suit::Clubs), vec![card!(2, C), card!(9, C)]);
+        assert!(player.cards_of_suit(Suit::Diamonds).is_empty());
+    }
+
+    #[test]
+    fn undo_place_restores_the_hand() {
+        let hand = vec![card!(2, C), card!(K, H), card!(9, C), card!(Q, S)];
+        let player = Player::restore(PlayerId(0), "Alice".to_owned(), hand.clone(), 0, vec![]);
+
+        let placed = player.place(&card!(9, C)).unwrap();
+        assert_eq!(player.hand().len(), 3);
+        player.undo_place(placed);
+
+        assert_eq!(*player.hand(), hand);
+    }
+
+    #[test]
+    fn sorted_hand_groups_by_suit() {
+        let hand = vec![card!(K, H), card!(2, S), card!(9, C), card!(A, D), card!(3, H), card!(2, C)];
+        let player = Player::restore(PlayerId(0), "Alice".to_owned(), hand, 0, vec![]);
+
+        assert_eq!(
+            player.sorted_hand(),
+            vec![card!(2, C), card!(9, C), card!(A, D), card!(3, H), card!(K, H), card!(2, S)]
+        );
+    }
+
+    #[test]
+    fn legal_moves_match_the_rule_function() {
+        let hand = vec![card!(2, C), card!(9, C), card!(Q, S), card!(4, D), card!(K, H)];
+        let player = Player::restore(PlayerId(0), "Alice".to_owned(), hand.clone(), 0, vec![]);
+        let tables = [vec![], vec![(PlayerId(1), card!(5, C))], vec![(PlayerId(1), card!(5, H))]];
+
+        for table in &tables {
+            for (is_first_trick, hearts_broken, queen_allowed) in
+                (0..8).map(|bits| (bits & 1 == 1, bits & 2 == 2, bits & 4 == 4))
+            {
+                assert_eq!(
+                    player.legal_moves(table, is_first_trick, hearts_broken, queen_allowed),
+                    legal_moves(&hand, table, is_first_trick, hearts_broken, queen_allowed)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn names_must_be_unique_and_non_empty() {
+        let names = |names: &[&str]| names.iter().map(|name| name.to_string()).collect::<Vec<_>>();
+
+        assert!(are_valid_names(&names(&["Alice", "Bob", "Carol"])));
+        assert!(!are_valid_names(&names(&["Alice", "Bob", "Alice"])));
+        assert!(!are_valid_names(&names(&["Alice", " BOB", "bob "])));
+        assert!(!are_valid_names(&names(&["Alice", "", "Carol"])));
+    }
+
+    #[test]
+    fn suggested_pass_is_the_highest_cards() {
+        let hand = vec![card!(2, C), card!(K, H), card!(9, C), card!(A, D), card!(Q, S), card!(5, S)];
+        let player = Player::restore(PlayerId(0), "Alice".to_owned(), hand, 0, vec![]);
+
+        assert_eq!(player.suggested_pass(3, true), vec![card!(A, D), card!(K, H), card!(Q, S)]);
+        assert_eq!(player.suggested_pass(3, false), vec![card!(A, D), card!(K, H), card!(9, C)]);
+    }
+
+    #[test]
+    fn passing_an_unheld_card_is_rejected() {
+        let hand = vec![card!(2, C), card!(K, H), card!(9, C), card!(Q, S)];
+        let player = Player::restore(PlayerId(0), "Alice".to_owned(), hand.clone(), 0, vec![]);
+
+        assert_eq!(player.pass(&[card!(2, C), card!(A, D)]), None);
+        assert_eq!(*player.hand(), hand);
+        assert_eq!(player.pass(&[card!(2, C), card!(Q, S)]), Some(vec![card!(2, C), card!(Q, S)]));
+        assert_eq!(*player.hand(), vec![card!(K, H), card!(9, C)]);
+    }
+}
@@ -0,0 +1,50 @@
+use super::{card::Card, config::GameConfig, player::PlayerId};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlayerState {
+    pub name: String,
+    pub hand: Vec<Card>,
+    pub score: i16,
+    pub round_scores: Vec<i16>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameState {
+    pub players: Vec<PlayerState>,
+    pub passing_order_index: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub round_number: usize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub round: Option<RoundState>,
+    pub config: GameConfig,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoundState {
+    pub starting_id: PlayerId,
+    pub hearts_broken: bool,
+    pub table: Vec<(PlayerId, Card)>,
+    pub scores: Vec<i16>,
+    pub trick_winners: Vec<PlayerId>,
+    pub points_taken: Vec<u16>,
+    pub played: Vec<Card>,
+    pub kitty: Vec<Card>,
+}
+
+impl RoundState {
+    pub fn new(starting_id: PlayerId, player_count: usize) -> Self {
+        Self {
+            starting_id,
+            hearts_broken: false,
+            table: vec![],
+            scores: vec![0; player_count],
+            trick_winners: vec![],
+            points_taken: vec![0; player_count],
+            played: vec![],
+            kitty: vec![],
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::{cell::RefCell, collections::HashSet};
+
+use super::card::{Card, Suit};
+
+pub struct PlayedTracker {
+    deck: Vec<Card>,
+    played: RefCell<HashSet<Card>>,
+}
+
+impl PlayedTracker {
+    pub fn new(deck: Vec<Card>) -> Self {
+        Self { deck, played: RefCell::new(HashSet::new()) }
+    }
+
+    pub fn record(&self, card: Card) {
+        self.played.borrow_mut().insert(card);
+    }
+
+    pub fn reset(&self) {
+        self.played.borrow_mut().clear();
+    }
+
+    pub fn is_played(&self, card: &Card) -> bool {
+        self.played.borrow().contains(card)
+    }
+
+    pub fn played(&self) -> Vec<Card> {
+        self.deck.iter().filter(|card| self.is_played(card)).copied().collect()
+    }
+
+    pub fn remaining(&self) -> Vec<Card> {
+        self.deck.iter().filter(|card| !self.is_played(card)).copied().collect()
+    }
+
+    pub fn remaining_in_suit(&self, suit: Suit) -> Vec<Card> {
+        self.remaining().into_iter().filter(|card| card.suit == suit).collect()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::models::{
+    config::GameConfig,
+    controller::Controller,
+    game::{Game, GameError},
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SimulationStats {
+    pub games: usize,
+    pub rounds: usize,
+    pub moons: usize,
+    pub wins: Vec<usize>,
+    pub ties: usize,
+    pub average_scores: Vec<f64>,
+}
+
+impl SimulationStats {
+    pub fn moon_frequency(&self) -> f64 {
+        if self.rounds == 0 {
+            0.0
+        } else {
+            self.moons as f64 / self.rounds as f64
+        }
+    }
+}
+
+pub fn simulate<C: Controller>(
+    config: GameConfig, controller: impl Fn(u64) -> C, n: usize, base_seed: u64,
+) -> Result<SimulationStats, GameError> {
+    let mut rounds = 0;
+    let mut moons = 0;
+    let mut wins = vec![0; config.player_count];
+    let mut ties = 0;
+    let mut total_scores = vec![0.0; config.player_count];
+
+    for i in 0..n {
+        let seed = base_seed + i as u64;
+        let mut game = Game::new(GameConfig { seed: Some(seed), ..config }, controller(seed))?;
+        while !game.is_over() {
+            let round_result = game.round()?;
+            rounds += 1;
+            if round_result.moon_shooter.is_some() {
+                moons += 1;
+            }
+        }
+
+        for (j, player) in game.players().iter().enumerate() {
+            total_scores[j] += f64::from(player.score());
+        }
+        match game.winner()[..] {
+            [winner] => wins[winner.id().0] += 1,
+            _ => ties += 1,
+        }
+    }
+
+    Ok(SimulationStats {
+        games: n,
+        rounds,
+        moons,
+        wins,
+        ties,
+        average_scores: total_scores
+            .into_iter()
+            .map(|total| if n == 0 { 0.0 } else { total / n as f64 })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::controller::RandomController;
+
+    #[test]
+    fn random_games_add_up() {
+        let stats = simulate(GameConfig::default(), RandomController::with_seed, 50, 1).unwrap();
+
+        assert_eq!(stats.games, 50);
+        assert_eq!(stats.wins.iter().sum::<usize>() + stats.ties, 50);
+        assert!(stats.rounds >= 50);
+        assert!(stats.moons <= stats.rounds);
+        assert_eq!(stats.average_scores.len(), 4);
+    }
+
+    #[test]
+    fn seeded_simulations_are_reproducible() {
+        let first = simulate(GameConfig::default(), RandomController::with_seed, 5, 9).unwrap();
+        let second = simulate(GameConfig::default(), RandomController::with_seed, 5, 9).unwrap();
+
+        assert_eq!(first, second);
+    }
+}
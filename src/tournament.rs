@@ -0,0 +1,131 @@
+use crate::models::{
+    config::GameConfig,
+    controller::Controller,
+    game::{Game, GameError, GameOutcome},
+    observer::Observer,
+    player::PlayerId,
+};
+
+pub struct Match {
+    config: GameConfig,
+    games: usize,
+    base_seed: u64,
+    names: Vec<String>,
+    wins: Vec<usize>,
+    games_played: usize,
+}
+
+impl Match {
+    pub fn new(config: GameConfig, games: usize, base_seed: u64) -> Self {
+        Self { config, games, base_seed, names: vec![], wins: vec![0; config.player_count], games_played: 0 }
+    }
+
+    pub fn names(&self) -> &[String] {
+        &self.names
+    }
+
+    pub fn wins(&self) -> &[usize] {
+        &self.wins
+    }
+
+    pub fn games_played(&self) -> usize {
+        self.games_played
+    }
+
+    pub fn is_over(&self) -> bool {
+        self.games_played >= self.games
+    }
+
+    pub fn winner(&self) -> Vec<PlayerId> {
+        if !self.is_over() {
+            return vec![];
+        }
+
+        let most_wins = self.wins.iter().copied().max().unwrap_or_default();
+        (0..self.wins.len()).filter(|i| self.wins[*i] == most_wins).map(PlayerId).collect()
+    }
+
+    pub fn play_game<C: Controller>(&mut self, controller: C) -> Result<GameOutcome, GameError> {
+        let seed = self.base_seed + self.games_played as u64;
+        let game = Game::new(GameConfig { seed: Some(seed), ..self.config }, controller)?;
+        self.finish_game(game)
+    }
+
+    fn finish_game<C: Controller>(&mut self, mut game: Game<C>) -> Result<GameOutcome, GameError> {
+        let outcome = game.play()?;
+
+        self.names = outcome.names.clone();
+        for winner in &outcome.winners {
+            self.wins[winner.0] += 1;
+        }
+        self.games_played += 1;
+        game.controller().display_match_standings(&self.names, &self.wins);
+
+        Ok(outcome)
+    }
+
+    pub fn play<C: Controller>(&mut self, controller: impl Fn() -> C) -> Result<Vec<PlayerId>, GameError> {
+        while !self.is_over() {
+            self.play_game(controller())?;
+        }
+
+        Ok(self.winner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use strum::IntoEnumIterator;
+
+    use super::*;
+    use crate::models::{
+        card::{Card, Rank},
+        controller::ScriptedController,
+        deck::{fixtures::SUITS, Deck},
+    };
+
+    /// A one-round game where the seat dealt every club wins each trick and shoots the moon.
+    fn moon_game(config: GameConfig, clubs_seat: usize) -> Game<ScriptedController> {
+        let suit = |seat: usize| SUITS[(seat + 4 - clubs_seat) % 4];
+        let deck =
+            (0..4).flat_map(|seat| Rank::iter().map(move |rank| Card::new(rank, suit(seat)))).collect();
+        let placements = Rank::iter()
+            .flat_map(|rank| (0..4).map(move |offset| Card::new(rank, suit((clubs_seat + offset) % 4))))
+            .collect();
+        let names = (1..=4).map(|i| format!("Player {i}")).collect();
+        let controller = ScriptedController::new(names, vec![], placements);
+
+        Game::new(config, controller).unwrap().with_deck(Deck::stacked(deck))
+    }
+
+    #[test]
+    fn three_game_match_declares_the_player_with_most_wins() {
+        let config = GameConfig::builder().no_passing(true).target_score(20).build().unwrap();
+        let mut tournament = Match::new(config, 3, 96);
+
+        for clubs_seat in [0, 2, 0] {
+            let outcome = tournament.finish_game(moon_game(config, clubs_seat)).unwrap();
+            assert_eq!(outcome.winners, vec![PlayerId(clubs_seat)]);
+        }
+
+        assert_eq!(tournament.games_played(), 3);
+        assert!(tournament.is_over());
+        assert_eq!(tournament.wins(), [2, 0, 1, 0]);
+        assert_eq!(tournament.winner(), vec![PlayerId(0)]);
+        assert_eq!(tournament.names(), ["Player 1", "Player 2", "Player 3", "Player 4"]);
+    }
+
+    #[test]
+    fn winner_is_only_declared_once_the_match_is_over() {
+        let mut tournament = Match::new(GameConfig::default(), 3, 0);
+        tournament.wins = vec![1, 1, 0, 0];
+        tournament.games_played = 2;
+
+        assert!(tournament.winner().is_empty());
+
+        tournament.wins = vec![1, 2, 0, 0];
+        tournament.games_played = 3;
+
+        assert_eq!(tournament.winner(), vec![PlayerId(1)]);
+    }
+}